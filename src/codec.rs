@@ -0,0 +1,511 @@
+// Codec
+//
+// Levin framing of the P2P messages. The decoder buffers the
+// incoming bytes until a complete message (header + payload) is
+// available, so it works with any AsyncRead through FramedRead.
+// Fragmented messages are reassembled before being returned.
+// The length of the messages is checked against the PacketLimits
+// before any memory is reserved for them. A PayloadCheck can parse
+// the payload while it is received and reject it early
+//
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::epee::{DecodeLimits, Event, StreamParser, Value};
+use crate::error::ConnectNodeError;
+use crate::fragment::{split_message, FragmentAssembler};
+use crate::protocol::{
+    Command, Header, LevinFlags, P2PMessage, HEADER_SIZE, LEVIN_PROTOCOL_VERSION, LEVIN_SIGNATURE,
+};
+
+// Same value as LEVIN_DEFAULT_MAX_PACKET_SIZE in monerod
+pub const DEFAULT_MAX_MESSAGE_LENGTH: u64 = 100_000_000;
+// Same value as LEVIN_INITIAL_MAX_PACKET_SIZE in monerod
+pub const DEFAULT_MAX_PRE_HANDSHAKE_LENGTH: u64 = 256 * 1024;
+
+// Maximum length of the received messages
+#[derive(Debug, Clone)]
+pub struct PacketLimits {
+    // Any message, fragmented or not
+    pub max_message_length: u64,
+    // Any message received before the handshake is completed
+    pub max_pre_handshake_length: u64,
+    // Complete messages of a command, after the handshake
+    pub max_command_length: HashMap<Command, u64>,
+}
+
+impl Default for PacketLimits {
+    // Values of get_max_bytes() in monerod
+    fn default() -> Self {
+        let max_command_length = HashMap::from([
+            (Command::Handshake, 65536),
+            (Command::TimedSync, 65536),
+            (Command::Ping, 4096),
+            (Command::SupportFlags, 4096),
+            (Command::NewBlock, 1024 * 1024 * 128),
+            (Command::NewTransactions, 1024 * 1024 * 128),
+            (Command::RequestGetObjects, 1024 * 1024 * 2),
+            (Command::ResponseGetObjects, 1024 * 1024 * 128),
+            (Command::RequestChain, 512 * 1024),
+            (Command::ResponseChainEntry, 1024 * 1024 * 4),
+            (Command::NewFluffyBlock, 1024 * 1024 * 4),
+            (Command::RequestFluffyMissingTx, 1024 * 1024),
+            (Command::GetTxPoolComplement, 1024 * 1024 * 4),
+        ]);
+
+        PacketLimits {
+            max_message_length: DEFAULT_MAX_MESSAGE_LENGTH,
+            max_pre_handshake_length: DEFAULT_MAX_PRE_HANDSHAKE_LENGTH,
+            max_command_length,
+        }
+    }
+}
+
+impl PacketLimits {
+    pub fn set_command_limit(&mut self, in_command: Command, in_max_length: u64) {
+        self.max_command_length.insert(in_command, in_max_length);
+    }
+
+    // Maximum length of a message of this command. The fragments and the
+    // unknown commands only have the global limit
+    pub fn max_length(&self, in_command: Command, in_handshake_completed: bool) -> u64 {
+        if !in_handshake_completed {
+            return self.max_pre_handshake_length.min(self.max_message_length);
+        }
+
+        match self.max_command_length.get(&in_command) {
+            Some(max) => (*max).min(self.max_message_length),
+            None => self.max_message_length,
+        }
+    }
+}
+
+// Check of the payloads while they are received
+pub trait PayloadCheck: fmt::Debug + Send {
+    // The payload of this message is parsed. The fragments are not
+    fn accepts(&self, in_header: &Header) -> bool;
+
+    // Called with every event of the payload. The parser gives the
+    // path of the event and the bytes parsed so far. An error rejects
+    // the message
+    fn check(
+        &mut self,
+        in_header: &Header,
+        in_parser: &StreamParser,
+        in_event: &Event,
+    ) -> Result<(), ConnectNodeError>;
+}
+
+// Rejects a handshake response of another network as soon as its
+// network_id is received
+#[derive(Debug)]
+pub struct NetworkIdCheck {
+    expected: [u8; 16],
+}
+
+impl NetworkIdCheck {
+    pub fn new(in_network_id: [u8; 16]) -> Self {
+        NetworkIdCheck {
+            expected: in_network_id,
+        }
+    }
+}
+
+impl PayloadCheck for NetworkIdCheck {
+    fn accepts(&self, in_header: &Header) -> bool {
+        in_header.command == Command::Handshake && in_header.flags.contains(LevinFlags::RESPONSE)
+    }
+
+    fn check(
+        &mut self,
+        _in_header: &Header,
+        in_parser: &StreamParser,
+        in_event: &Event,
+    ) -> Result<(), ConnectNodeError> {
+        if let Event::Value(Value::String(v)) = in_event {
+            if in_parser.path() == "node_data.network_id" && v[..] != self.expected {
+                return Err(ConnectNodeError::NetworkIdMismatch {
+                    expected: self.expected,
+                    received: v[..]
+                        .try_into()
+                        .map_err(|_| ConnectNodeError::InvalidInput("Invalid network id"))?,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct LevinCodec {
+    limits: PacketLimits,
+    // Set by the owner of the connection when the handshake is completed
+    handshake_completed: Arc<AtomicBool>,
+    // Header of the message whose payload is being received
+    current_header: Option<Header>,
+    fragments: FragmentAssembler,
+    // If set, the outgoing notifications are split in fragments of this size
+    fragment_size: Option<usize>,
+    payload_check: Option<Box<dyn PayloadCheck>>,
+    decode_limits: DecodeLimits,
+    // Parser of the payload being received and bytes given to it
+    payload_parser: Option<StreamParser>,
+    payload_received: usize,
+}
+
+impl Default for LevinCodec {
+    fn default() -> Self {
+        LevinCodec::new(PacketLimits::default())
+    }
+}
+
+impl LevinCodec {
+    pub fn new(in_limits: PacketLimits) -> Self {
+        LevinCodec {
+            fragments: FragmentAssembler::new(in_limits.max_message_length),
+            limits: in_limits,
+            handshake_completed: Arc::new(AtomicBool::new(false)),
+            current_header: None,
+            fragment_size: None,
+            payload_check: None,
+            decode_limits: DecodeLimits::default(),
+            payload_parser: None,
+            payload_received: 0,
+        }
+    }
+
+    // Flag shared with the owner of the connection. Until it is set, only
+    // short messages are accepted
+    pub fn handshake_flag(&self) -> Arc<AtomicBool> {
+        self.handshake_completed.clone()
+    }
+
+    pub fn set_handshake_completed(&self) {
+        self.handshake_completed.store(true, Ordering::Release);
+    }
+
+    pub fn with_fragment_size(mut self, in_fragment_size: usize) -> Self {
+        self.fragment_size = Some(in_fragment_size);
+        self
+    }
+
+    pub fn with_payload_check(
+        mut self,
+        in_check: impl PayloadCheck + 'static,
+        in_limits: &DecodeLimits,
+    ) -> Self {
+        self.payload_check = Some(Box::new(in_check));
+        self.decode_limits = *in_limits;
+        self
+    }
+
+    fn check_header(&self, in_header: &Header) -> Result<(), ConnectNodeError> {
+        if in_header.signature != LEVIN_SIGNATURE {
+            return Err(ConnectNodeError::InvalidSignature(in_header.signature));
+        }
+
+        if in_header.version != LEVIN_PROTOCOL_VERSION {
+            return Err(ConnectNodeError::InvalidVersion(in_header.version));
+        }
+
+        if in_header.msg_length > self.limits.max_message_length {
+            return Err(ConnectNodeError::MessageTooLong {
+                command: in_header.command,
+                length: in_header.msg_length,
+                max: self.limits.max_message_length,
+            });
+        }
+
+        Ok(())
+    }
+
+    // Check the length of a received message, complete or fragment
+    fn check_length(&self, in_header: &Header) -> Result<(), ConnectNodeError> {
+        let handshake_completed = self.handshake_completed.load(Ordering::Acquire);
+        let max = self
+            .limits
+            .max_length(in_header.command, handshake_completed);
+
+        if in_header.msg_length > max {
+            return Err(ConnectNodeError::MessageTooLong {
+                command: in_header.command,
+                length: in_header.msg_length,
+                max,
+            });
+        }
+
+        Ok(())
+    }
+
+    // Called when the header of a bucket is read
+    fn start_payload(&mut self, in_header: &Header) {
+        let fragment = in_header
+            .flags
+            .intersects(LevinFlags::BEGIN | LevinFlags::END);
+
+        self.payload_parser = match &self.payload_check {
+            Some(c) if !fragment && c.accepts(in_header) => {
+                Some(StreamParser::new(&self.decode_limits))
+            }
+            _ => None,
+        };
+        self.payload_received = 0;
+    }
+
+    // Parse the bytes of the payload received since the last call
+    fn check_payload(
+        &mut self,
+        in_header: &Header,
+        in_payload: &[u8],
+    ) -> Result<(), ConnectNodeError> {
+        let (Some(parser), Some(check)) = (&mut self.payload_parser, &mut self.payload_check)
+        else {
+            return Ok(());
+        };
+
+        parser.feed(&in_payload[self.payload_received..]);
+        self.payload_received = in_payload.len();
+
+        while let Some(e) = parser.next_event()? {
+            check.check(in_header, parser, &e)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LevinCodec {
+    // Read one Levin bucket. It can be a fragment of a bigger message
+    fn decode_bucket(
+        &mut self,
+        src: &mut BytesMut,
+    ) -> Result<Option<P2PMessage>, ConnectNodeError> {
+        let header = match self.current_header.take() {
+            Some(h) => h,
+            None => {
+                if src.len() < HEADER_SIZE as usize {
+                    return Ok(None);
+                }
+
+                let header = Header::from_bytes(&src[..HEADER_SIZE as usize])?;
+                self.check_header(&header)?;
+                self.check_length(&header)?;
+
+                src.advance(HEADER_SIZE as usize);
+                self.start_payload(&header);
+                header
+            }
+        };
+
+        let msg_length = header.msg_length as usize;
+        self.check_payload(&header, &src[..src.len().min(msg_length)])?;
+
+        if src.len() < msg_length {
+            // Wait for the rest of the payload
+            src.reserve(msg_length - src.len());
+            self.current_header = Some(header);
+            return Ok(None);
+        }
+
+        self.payload_parser = None;
+
+        // No copy, the payload keeps a reference to the receive buffer
+        let payload = src.split_to(msg_length).freeze();
+
+        Ok(Some(P2PMessage {
+            header,
+            buffer: payload,
+        }))
+    }
+}
+
+impl Decoder for LevinCodec {
+    type Item = P2PMessage;
+    type Error = ConnectNodeError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        loop {
+            let bucket = match self.decode_bucket(src)? {
+                Some(b) => b,
+                None => return Ok(None),
+            };
+
+            let handshake_completed = self.handshake_completed.load(Ordering::Acquire);
+            let limits = &self.limits;
+            let max_length = |c| limits.max_length(c, handshake_completed);
+
+            if let Some(m) = self.fragments.push(bucket, max_length)? {
+                // The header of a fragmented message was not checked yet
+                self.check_length(&m.header)?;
+                return Ok(Some(m));
+            }
+        }
+    }
+}
+
+impl Encoder<P2PMessage> for LevinCodec {
+    type Error = ConnectNodeError;
+
+    fn encode(&mut self, mut item: P2PMessage, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        item.header.msg_length = item.buffer.len() as u64;
+        self.check_header(&item.header)?;
+
+        match self.fragment_size {
+            // Only the notifications can be fragmented
+            Some(size) if item.header.expected_response == 0 => {
+                for fragment in split_message(&item, size)? {
+                    fragment.write_to(dst);
+                }
+            }
+            _ => item.write_to(dst),
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn levin_codec_partial_frames() {
+    let mut message = P2PMessage::new_command(Command::Ping).unwrap();
+    message.buffer = vec![0x01, 0x11, 0x01, 0x01, 0x01, 0x01, 0x02, 0x01, 0x01, 0x00].into();
+
+    let mut codec = LevinCodec::default();
+    let mut encoded = BytesMut::new();
+    codec.encode(message, &mut encoded).unwrap();
+
+    // Feed the frame one byte at a time
+    let mut input = BytesMut::new();
+    let mut decoded = None;
+    for b in encoded.iter() {
+        assert!(decoded.is_none());
+        input.extend_from_slice(&[*b]);
+        decoded = codec.decode(&mut input).unwrap();
+    }
+
+    let decoded = decoded.unwrap();
+    assert_eq!(10, decoded.header.msg_length);
+    assert_eq!(Command::Ping, decoded.header.command);
+    assert_eq!(0x02, decoded.buffer[6]);
+    assert!(input.is_empty());
+
+    // Reject messages longer than the limit
+    let mut header = Header::new_command(Command::Ping).unwrap();
+    header.msg_length = DEFAULT_MAX_MESSAGE_LENGTH + 1;
+    let mut input = BytesMut::from(&header.to_bytes()[..]);
+    assert!(matches!(
+        codec.decode(&mut input),
+        Err(ConnectNodeError::MessageTooLong { .. })
+    ));
+
+    assert!(matches!(
+        Header::from_bytes(&LEVIN_SIGNATURE),
+        Err(ConnectNodeError::HeaderTooShort(8))
+    ));
+}
+
+#[test]
+fn levin_codec_limits() {
+    let mut codec = LevinCodec::default();
+
+    // Before the handshake
+    let mut header = Header::new_command(Command::Handshake).unwrap();
+    header.msg_length = DEFAULT_MAX_PRE_HANDSHAKE_LENGTH + 1;
+    let mut input = BytesMut::from(&header.to_bytes()[..]);
+    assert!(matches!(
+        codec.decode(&mut input),
+        Err(ConnectNodeError::MessageTooLong {
+            max: DEFAULT_MAX_PRE_HANDSHAKE_LENGTH,
+            ..
+        })
+    ));
+
+    // After the handshake, a block is allowed but not a long ping
+    codec.handshake_flag().store(true, Ordering::Release);
+
+    let mut header = P2PMessage::notify(Command::NewBlock, Vec::new())
+        .unwrap()
+        .header;
+    header.msg_length = DEFAULT_MAX_PRE_HANDSHAKE_LENGTH + 1;
+    let mut input = BytesMut::from(&header.to_bytes()[..]);
+    assert!(codec.decode(&mut input).unwrap().is_none());
+
+    let mut codec = LevinCodec::default();
+    codec.set_handshake_completed();
+
+    let mut header = Header::new_command(Command::Ping).unwrap();
+    header.msg_length = 4097;
+    let mut input = BytesMut::from(&header.to_bytes()[..]);
+    assert!(matches!(
+        codec.decode(&mut input),
+        Err(ConnectNodeError::MessageTooLong {
+            command: Command::Ping,
+            max: 4096,
+            ..
+        })
+    ));
+
+    // The limit of a fragmented message is checked with the header in
+    // the first fragment, before it is reassembled
+    let mut codec = LevinCodec::default();
+    codec.set_handshake_completed();
+
+    let mut message = P2PMessage::new_command(Command::Ping).unwrap();
+    message.buffer = vec![0; 5000].into();
+    message.header.msg_length = message.buffer.len() as u64;
+
+    let mut input = BytesMut::new();
+    for f in split_message(&message, 1024).unwrap() {
+        f.write_to(&mut input);
+    }
+    assert!(matches!(
+        codec.decode(&mut input),
+        Err(ConnectNodeError::FragmentedMessageTooLong {
+            length: 5000,
+            max: 4096
+        })
+    ));
+}
+
+#[test]
+fn levin_codec_payload_check() {
+    use crate::protocol::HandshakeResponse;
+
+    let mut response = HandshakeResponse::new();
+    response.node_data.network_id = [1; 16];
+    let mut message = P2PMessage::new();
+    message.header = Header::new_response(Command::Handshake).unwrap();
+    message.buffer = crate::epee::to_bytes(&response).unwrap().into();
+
+    let mut encoded = BytesMut::new();
+    LevinCodec::default().encode(message, &mut encoded).unwrap();
+
+    // Same network
+    let mut codec = LevinCodec::default()
+        .with_payload_check(NetworkIdCheck::new([1; 16]), &DecodeLimits::default());
+    let mut input = encoded.clone();
+    assert!(codec.decode(&mut input).unwrap().is_some());
+
+    // The message is rejected before the rest of the payload arrives
+    let mut codec = LevinCodec::default()
+        .with_payload_check(NetworkIdCheck::new([2; 16]), &DecodeLimits::default());
+    let position = encoded.windows(16).position(|w| w == [1; 16]).unwrap();
+    let mut input = BytesMut::from(&encoded[..position + 16]);
+    assert!(matches!(
+        codec.decode(&mut input),
+        Err(ConnectNodeError::NetworkIdMismatch {
+            received: [1, ..],
+            ..
+        })
+    ));
+}
@@ -0,0 +1,297 @@
+// Dispatcher
+//
+// Sends requests to the node and routes the responses back to the
+// callers. A Levin response has no request id, only the command, so
+// the requests waiting for a response are kept in order per command,
+// as monerod does. When the connection is closed, the requests still
+// waiting fail with the error of the reader
+//
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::timeout,
+};
+
+use crate::error::ConnectNodeError;
+use crate::protocol::{Command, LevinFlags, P2PMessage};
+
+// Same value as P2P_DEFAULT_INVOKE_TIMEOUT in monerod
+pub const DEFAULT_INVOKE_TIMEOUT: Duration = Duration::from_secs(120);
+
+struct PendingRequest {
+    id: u64,
+    response: oneshot::Sender<Result<P2PMessage, ConnectNodeError>>,
+}
+
+#[derive(Default)]
+struct PendingRequests {
+    next_id: u64,
+    closed: bool,
+    by_command: HashMap<Command, VecDeque<PendingRequest>>,
+}
+
+#[derive(Clone)]
+pub struct Dispatcher {
+    outgoing: mpsc::Sender<P2PMessage>,
+    pending: Arc<Mutex<PendingRequests>>,
+    timeouts: HashMap<Command, Duration>,
+    default_timeout: Duration,
+}
+
+impl Dispatcher {
+    // The messages are written to the connection by the task reading
+    // from in_outgoing
+    pub fn new(in_outgoing: mpsc::Sender<P2PMessage>) -> Self {
+        Dispatcher {
+            outgoing: in_outgoing,
+            pending: Arc::new(Mutex::new(PendingRequests::default())),
+            timeouts: HashMap::new(),
+            default_timeout: DEFAULT_INVOKE_TIMEOUT,
+        }
+    }
+
+    pub fn set_timeout(&mut self, in_command: Command, in_timeout: Duration) {
+        self.timeouts.insert(in_command, in_timeout);
+    }
+
+    pub fn set_default_timeout(&mut self, in_timeout: Duration) {
+        self.default_timeout = in_timeout;
+    }
+
+    pub fn timeout(&self, in_command: Command) -> Duration {
+        *self
+            .timeouts
+            .get(&in_command)
+            .unwrap_or(&self.default_timeout)
+    }
+
+    // Send a request and wait for its response
+    pub async fn invoke(
+        &self,
+        in_command: Command,
+        in_payload: impl Into<Bytes>,
+    ) -> Result<P2PMessage, ConnectNodeError> {
+        let mut request = P2PMessage::new_command(in_command)?;
+        request.buffer = in_payload.into();
+        request.header.msg_length = request.buffer.len() as u64;
+
+        let (response_tx, response_rx) = oneshot::channel();
+
+        let id = {
+            let mut pending = self.pending.lock().unwrap();
+            if pending.closed {
+                return Err(ConnectNodeError::ConnectionClosed);
+            }
+            let id = pending.next_id;
+            pending.next_id += 1;
+            pending
+                .by_command
+                .entry(in_command)
+                .or_default()
+                .push_back(PendingRequest {
+                    id,
+                    response: response_tx,
+                });
+            id
+        };
+
+        if self.outgoing.send(request).await.is_err() {
+            self.remove_pending(in_command, id);
+            return Err(ConnectNodeError::ConnectionClosed);
+        }
+
+        let invoke_timeout = self.timeout(in_command);
+
+        match timeout(invoke_timeout, response_rx).await {
+            Ok(Ok(r)) => r,
+            // The dispatcher was dropped
+            Ok(Err(_)) => Err(ConnectNodeError::ConnectionClosed),
+            Err(_) => {
+                self.remove_pending(in_command, id);
+                Err(ConnectNodeError::Timeout {
+                    command: in_command,
+                    timeout: invoke_timeout,
+                })
+            }
+        }
+    }
+
+    // Send a cryptonote notification
+    pub async fn notify(
+        &self,
+        in_command: Command,
+        in_payload: impl Into<Bytes>,
+    ) -> Result<(), ConnectNodeError> {
+        self.send(P2PMessage::notify(in_command, in_payload)?).await
+    }
+
+    // Send a message that does not wait for a response
+    pub async fn send(&self, in_message: P2PMessage) -> Result<(), ConnectNodeError> {
+        self.outgoing
+            .send(in_message)
+            .await
+            .map_err(|_| ConnectNodeError::ConnectionClosed)
+    }
+
+    // Deliver a response to the oldest request with the same command.
+    // Requests and notifications are returned to be processed by the caller
+    pub fn route(&self, in_message: P2PMessage) -> Result<Option<P2PMessage>, ConnectNodeError> {
+        let is_response = in_message.header.flags.contains(LevinFlags::RESPONSE)
+            && in_message.header.expected_response == 0;
+
+        if !is_response {
+            return Ok(Some(in_message));
+        }
+
+        let command = in_message.header.command;
+        let waiting_request = self
+            .pending
+            .lock()
+            .unwrap()
+            .by_command
+            .get_mut(&command)
+            .and_then(|q| q.pop_front());
+
+        match waiting_request {
+            Some(r) => r
+                .response
+                .send(Ok(in_message))
+                .map(|_| None)
+                .map_err(|_| ConnectNodeError::UnexpectedResponse { command }),
+            None => Err(ConnectNodeError::UnexpectedResponse { command }),
+        }
+    }
+
+    // Fail the requests waiting for a response, once the connection is
+    // closed. The next requests fail at once with ConnectionClosed
+    pub fn fail_all(&self, in_error: ConnectNodeError) {
+        let waiting_requests: Vec<PendingRequest> = {
+            let mut pending = self.pending.lock().unwrap();
+            pending.closed = true;
+            pending.by_command.drain().flat_map(|(_, q)| q).collect()
+        };
+
+        for r in waiting_requests {
+            // The caller may have stopped waiting
+            let _ = r.response.send(Err(copy_error(&in_error)));
+        }
+    }
+
+    fn remove_pending(&self, in_command: Command, in_id: u64) {
+        if let Some(q) = self.pending.lock().unwrap().by_command.get_mut(&in_command) {
+            q.retain(|r| r.id != in_id);
+        }
+    }
+}
+
+// The errors are not Clone because of the IO errors. The framing
+// errors and the wrong networks are copied, the others are reported
+// as ConnectionClosed
+fn copy_error(in_error: &ConnectNodeError) -> ConnectNodeError {
+    match in_error {
+        ConnectNodeError::HeaderTooShort(l) => ConnectNodeError::HeaderTooShort(*l),
+        ConnectNodeError::InvalidSignature(s) => ConnectNodeError::InvalidSignature(*s),
+        ConnectNodeError::InvalidVersion(v) => ConnectNodeError::InvalidVersion(*v),
+        ConnectNodeError::MessageTooLong {
+            command,
+            length,
+            max,
+        } => ConnectNodeError::MessageTooLong {
+            command: *command,
+            length: *length,
+            max: *max,
+        },
+        ConnectNodeError::InvalidFragment(m) => ConnectNodeError::InvalidFragment(m),
+        ConnectNodeError::FragmentedMessageTooLong { length, max } => {
+            ConnectNodeError::FragmentedMessageTooLong {
+                length: *length,
+                max: *max,
+            }
+        }
+        // Found by the codec in the handshake response
+        ConnectNodeError::NetworkIdMismatch { expected, received } => {
+            ConnectNodeError::NetworkIdMismatch {
+                expected: *expected,
+                received: *received,
+            }
+        }
+        _ => ConnectNodeError::ConnectionClosed,
+    }
+}
+
+#[tokio::test]
+async fn dispatcher_invoke() {
+    let (tx, mut rx) = mpsc::channel(8);
+    let mut dispatcher = Dispatcher::new(tx);
+    dispatcher.set_timeout(Command::TimedSync, Duration::from_millis(10));
+
+    // Only the pings are answered
+    let node = dispatcher.clone();
+    tokio::spawn(async move {
+        while let Some(request) = rx.recv().await {
+            if request.header.command == Command::Ping {
+                let mut response = P2PMessage::new();
+                response.header.command = request.header.command;
+                response.header.flags = LevinFlags::RESPONSE;
+                assert!(node.route(response).unwrap().is_none());
+            }
+        }
+    });
+
+    let response = dispatcher.invoke(Command::Ping, Vec::new()).await.unwrap();
+    assert_eq!(Command::Ping, response.header.command);
+
+    // Nobody answers
+    assert!(matches!(
+        dispatcher.invoke(Command::TimedSync, Vec::new()).await,
+        Err(ConnectNodeError::Timeout { .. })
+    ));
+
+    // Nobody is waiting for it
+    let mut response = P2PMessage::new();
+    response.header.command = Command::TimedSync;
+    response.header.flags = LevinFlags::RESPONSE;
+    assert!(matches!(
+        dispatcher.route(response),
+        Err(ConnectNodeError::UnexpectedResponse { .. })
+    ));
+
+    // Requests are returned to the caller
+    let request = P2PMessage::new_command(Command::Ping).unwrap();
+    assert!(dispatcher.route(request).unwrap().is_some());
+
+    // The connection is closed while waiting
+    let waiting = dispatcher.clone();
+    let closed = tokio::spawn(async move { waiting.invoke(Command::Handshake, Vec::new()).await });
+    while !dispatcher
+        .pending
+        .lock()
+        .unwrap()
+        .by_command
+        .contains_key(&Command::Handshake)
+    {
+        tokio::task::yield_now().await;
+    }
+    dispatcher.fail_all(ConnectNodeError::NetworkIdMismatch {
+        expected: [1; 16],
+        received: [2; 16],
+    });
+    assert!(matches!(
+        closed.await.unwrap(),
+        Err(ConnectNodeError::NetworkIdMismatch {
+            received: [2, ..],
+            ..
+        })
+    ));
+    assert!(matches!(
+        dispatcher.invoke(Command::Ping, Vec::new()).await,
+        Err(ConnectNodeError::ConnectionClosed)
+    ));
+}
@@ -0,0 +1,137 @@
+// Blobs
+//
+// Adapter for #[serde(with)] of the byte strings of any size, i.e.
+// the transactions and the blocks. They are epee strings, as the
+// hashes, but a Vec<u8> would be written as an array of U8. In JSON
+// they are hex strings
+//
+//   #[serde(with = "crate::epee::blob::list")]
+//   pub txs: Vec<Vec<u8>>,
+//
+
+use std::fmt;
+
+use serde::de::{self, SeqAccess, Unexpected, Visitor};
+use serde::{Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(in_blob: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex::encode(in_blob))
+    } else {
+        serializer.serialize_bytes(in_blob)
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BlobVisitor)
+    } else {
+        deserializer.deserialize_bytes(BlobVisitor)
+    }
+}
+
+struct BlobVisitor;
+
+impl Visitor<'_> for BlobVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a blob")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        hex::decode(v).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+// List of blobs. It is an array of strings
+pub mod list {
+    use super::*;
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Blob(#[serde(with = "super")] Vec<u8>);
+
+    pub fn serialize<S: Serializer>(
+        in_blobs: &[Vec<u8>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        struct BlobRef<'a>(&'a [u8]);
+
+        impl Serialize for BlobRef<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(self.0, serializer)
+            }
+        }
+
+        serializer.collect_seq(in_blobs.iter().map(|b| BlobRef(b)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        struct ListVisitor;
+
+        impl<'de> Visitor<'de> for ListVisitor {
+            type Value = Vec<Vec<u8>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a list of blobs")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut output = Vec::new();
+                while let Some(b) = seq.next_element::<Blob>()? {
+                    output.push(b.0);
+                }
+
+                Ok(output)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor)
+    }
+}
+
+#[test]
+fn epee_blob() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Blobs {
+        #[serde(with = "crate::epee::blob")]
+        one: Vec<u8>,
+        #[serde(with = "crate::epee::blob::list")]
+        list: Vec<Vec<u8>>,
+    }
+
+    let blobs = Blobs {
+        one: vec![1, 2, 3],
+        list: vec![vec![4], vec![5, 6]],
+    };
+
+    // A string of 3 bytes and an array of 2 strings
+    let bytes = crate::epee::to_bytes(&blobs).unwrap();
+    assert_eq!(
+        [
+            8, 3, b'o', b'n', b'e', 10, 12, 1, 2, 3, 4, b'l', b'i', b's', b't', 0x8a, 8, 4, 4, 8,
+            5, 6
+        ],
+        bytes[9..]
+    );
+    assert_eq!(
+        blobs,
+        crate::epee::from_bytes(&bytes, &Default::default()).unwrap()
+    );
+
+    let json = serde_json::to_string(&blobs).unwrap();
+    assert_eq!(r#"{"one":"010203","list":["04","0506"]}"#, json);
+    assert_eq!(blobs, serde_json::from_str(&json).unwrap());
+}
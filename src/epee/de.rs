@@ -0,0 +1,298 @@
+// Epee deserializer
+//
+// Serde data format of the portable storage. The marker of a value is
+// read before the value, so the type of the payload drives the
+// visitor. Strings are borrowed from the payload and the unknown
+// fields are skipped without recursion
+//
+
+use serde::de::{self, value::BorrowedStrDeserializer, DeserializeSeed, Visitor};
+use serde::forward_to_deserialize_any;
+
+use crate::epee::error::{Error, Result};
+use crate::epee::io::Reader;
+use crate::epee::marker::{InnerMarker, Marker};
+
+// Reads a value whose marker was already read
+pub struct Deserializer<'a, 'de> {
+    r: &'a mut Reader<'de>,
+    marker: Marker,
+}
+
+impl<'a, 'de> Deserializer<'a, 'de> {
+    pub fn new(r: &'a mut Reader<'de>, in_marker: Marker) -> Self {
+        Deserializer {
+            r,
+            marker: in_marker,
+        }
+    }
+
+    fn read_string(&mut self) -> Result<&'de [u8]> {
+        if self.marker != Marker::new(InnerMarker::String) {
+            return Err(Error::Format("Marker does not match the expected type"));
+        }
+
+        let length = self.r.read_string_length()?;
+        self.r.read_bytes(length)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_, 'de> {
+    type Error = Error;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.marker.is_seq {
+            let length = self.r.read_array_length(self.marker)?;
+            return visitor.visit_seq(SeqAccess {
+                r: self.r,
+                marker: self.marker.into_scalar(),
+                remaining: length,
+            });
+        }
+
+        match self.marker.inner {
+            InnerMarker::I64 => visitor.visit_i64(i64::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::I32 => visitor.visit_i32(i32::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::I16 => visitor.visit_i16(i16::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::I8 => visitor.visit_i8(i8::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::U64 => visitor.visit_u64(u64::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::U32 => visitor.visit_u32(u32::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::U16 => visitor.visit_u16(u16::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::U8 => visitor.visit_u8(self.r.read_u8()?),
+            InnerMarker::F64 => visitor.visit_f64(f64::from_le_bytes(self.r.read_array()?)),
+            InnerMarker::String => {
+                let length = self.r.read_string_length()?;
+                visitor.visit_borrowed_bytes(self.r.read_bytes(length)?)
+            }
+            InnerMarker::Bool => visitor.visit_bool(self.r.read_u8()? != 0),
+            InnerMarker::Object => {
+                self.r.enter()?;
+                self.r.add_object()?;
+                let number_fields = self.r.read_varint()?;
+                let value = visitor.visit_map(SectionAccess {
+                    r: &mut *self.r,
+                    remaining: number_fields,
+                })?;
+                self.r.leave();
+
+                Ok(value)
+            }
+            // Element of an array of arrays
+            InnerMarker::Array => {
+                let marker = Marker::try_from(self.r.read_u8()?)?;
+                if !marker.is_seq {
+                    return Err(Error::Format("Array element is not an array"));
+                }
+
+                self.r.enter()?;
+                let value = Deserializer::new(&mut *self.r, marker).deserialize_any(visitor)?;
+                self.r.leave();
+
+                Ok(value)
+            }
+        }
+    }
+
+    // A field that is in the payload is never None
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // Only enums without values, written as a string
+    fn deserialize_enum<V: Visitor<'de>>(
+        mut self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let variant = std::str::from_utf8(self.read_string()?)
+            .map_err(|_| Error::Value("String is not valid UTF-8"))?;
+        visitor.visit_enum(BorrowedStrDeserializer::new(variant))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        skip_epee_value(self.r, self.marker)?;
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+struct SeqAccess<'a, 'de> {
+    r: &'a mut Reader<'de>,
+    marker: Marker,
+    remaining: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+
+        seed.deserialize(Deserializer::new(&mut *self.r, self.marker))
+            .map(Some)
+    }
+
+    // The number of elements comes from the peer
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining.min(self.r.remaining()))
+    }
+}
+
+struct SectionAccess<'a, 'de> {
+    r: &'a mut Reader<'de>,
+    remaining: u64,
+}
+
+impl<'de> de::MapAccess<'de> for SectionAccess<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+
+        let name = self.r.read_name()?;
+        seed.deserialize(BorrowedStrDeserializer::new(name))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let marker = Marker::try_from(self.r.read_u8()?)?;
+        seed.deserialize(Deserializer::new(&mut *self.r, marker))
+    }
+}
+
+enum Skip {
+    Value(Marker),
+    Fields(u64),
+    Elements(Marker, u64),
+}
+
+// Skip a value of any type. The pending sections and arrays are kept
+// in a list instead of the call stack
+pub fn skip_epee_value(r: &mut Reader, in_marker: Marker) -> Result<()> {
+    let mut pending: Vec<Skip> = vec![Skip::Value(in_marker)];
+
+    while let Some(next) = pending.pop() {
+        match next {
+            Skip::Value(marker) if marker.is_seq => {
+                let length = r.read_array_length(marker)?;
+                pending.push(Skip::Elements(marker.into_scalar(), length as u64));
+            }
+            Skip::Value(marker) => match marker.inner {
+                InnerMarker::String => {
+                    let length = r.read_string_length()?;
+                    r.read_bytes(length)?;
+                }
+                InnerMarker::Object => {
+                    r.add_object()?;
+                    let number_fields = r.read_varint()?;
+                    pending.push(Skip::Fields(number_fields));
+                }
+                // Every element of an array of arrays has its own marker
+                InnerMarker::Array => {
+                    let element_marker = Marker::try_from(r.read_u8()?)?;
+                    if !element_marker.is_seq {
+                        return Err(Error::Format("Array element is not an array"));
+                    }
+                    pending.push(Skip::Value(element_marker));
+                }
+                scalar => {
+                    r.read_bytes(scalar.size().unwrap_or_default())?;
+                }
+            },
+            Skip::Fields(0) | Skip::Elements(_, 0) => {}
+            Skip::Fields(remaining) => {
+                pending.push(Skip::Fields(remaining - 1));
+                r.read_name()?;
+                let marker = Marker::try_from(r.read_u8()?)?;
+                pending.push(Skip::Value(marker));
+            }
+            Skip::Elements(marker, remaining) => {
+                pending.push(Skip::Elements(marker, remaining - 1));
+                pending.push(Skip::Value(marker));
+            }
+        }
+
+        // One entry per level, with the rest of the container. They are
+        // below the levels the reader already entered
+        let max_depth = r.limits().max_depth;
+        if r.depth() + pending.len() > max_depth {
+            return Err(Error::DepthExceeded(max_depth));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn epee_skip() {
+    // { "a": [[1, 2]], "b": { "c": "xy" } }
+    let payload = [
+        8, 1, b'a', 0x8d, 4, 0x88, 8, 1, 2, 1, b'b', 12, 4, 1, b'c', 10, 8, b'x', b'y',
+    ];
+    let mut r = Reader::new(&payload);
+    skip_epee_value(&mut r, Marker::new(InnerMarker::Object)).unwrap();
+    assert_eq!(0, r.remaining());
+
+    // Sections nested deeper than the limit
+    use crate::epee::io::MAX_DEPTH;
+    let mut payload = Vec::new();
+    for _ in 0..=MAX_DEPTH {
+        payload.extend_from_slice(&[4, 1, b'a', 12]);
+    }
+    payload.push(0);
+    let mut r = Reader::new(&payload);
+    assert!(matches!(
+        skip_epee_value(&mut r, Marker::new(InnerMarker::Object)),
+        Err(Error::DepthExceeded(_))
+    ));
+
+    // The levels already entered count. One section fits, not two
+    let nested = [4, 1, b'a', 12, 0];
+    for (payload, fits) in [(&nested[4..], true), (&nested[..], false)] {
+        let mut r = Reader::new(payload);
+        for _ in 0..MAX_DEPTH - 1 {
+            r.enter().unwrap();
+        }
+        let result = skip_epee_value(&mut r, Marker::new(InnerMarker::Object));
+        assert_eq!(fits, result.is_ok());
+    }
+
+    // Only the known fields are read, the rest are skipped
+    #[derive(serde::Deserialize)]
+    struct Known {
+        c: String,
+    }
+
+    // { "a": [[1, 2]], "c": "xy" }
+    let mut bytes = Vec::new();
+    crate::epee::write_header(&mut bytes);
+    bytes.extend_from_slice(&[
+        8, 1, b'a', 0x8d, 4, 0x88, 8, 1, 2, 1, b'c', 10, 8, b'x', b'y',
+    ]);
+    let known: Known = crate::epee::from_bytes(&bytes, &Default::default()).unwrap();
+    assert_eq!("xy", known.c);
+}
@@ -0,0 +1,326 @@
+// Dump
+//
+// Annotated hex dump of a payload. Every field is listed with its
+// offset, its bytes, its type code, its path in the payload, i.e.
+// local_peerlist_new[17].adr.addr.m_ip, and its value. Sections and
+// arrays only take the bytes of their header, so the entries follow
+// one after the other. If the payload is not valid, the dump stops at
+// the offset of the error
+//
+
+use std::fmt;
+
+use crate::epee::error::{Error, Result};
+use crate::epee::io::Reader;
+use crate::epee::limits::DecodeLimits;
+use crate::epee::marker::{InnerMarker, Marker};
+use crate::epee::{read_header, PORTABLE_STORAGE_HEADER_SIZE};
+
+// Bytes and characters shown in a line
+const MAX_DUMP_BYTES: usize = 12;
+const MAX_DUMP_VALUE: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DumpEntry {
+    pub offset: usize,
+    pub length: usize,
+    pub path: String,
+    // Marker of the value, with the array flag. None for the header
+    pub type_code: Option<u8>,
+    pub value: String,
+}
+
+#[derive(Debug)]
+pub struct Dump<'a> {
+    buffer: &'a [u8],
+    pub entries: Vec<DumpEntry>,
+    // Offset of the first byte after the root section
+    pub end: usize,
+    // Offset where the decoding stopped and the reason
+    pub error: Option<(usize, Error)>,
+}
+
+// Dump of a payload, with the storage header
+pub fn annotate<'a>(in_buffer: &'a [u8], in_limits: &DecodeLimits) -> Dump<'a> {
+    let mut walker = Walker {
+        r: Reader::with_limits(in_buffer, in_limits),
+        entries: Vec::new(),
+    };
+
+    let result = walker.walk_payload();
+    let end = walker.r.position();
+
+    Dump {
+        buffer: in_buffer,
+        entries: walker.entries,
+        end,
+        error: result.err().map(|e| (end, e)),
+    }
+}
+
+struct Walker<'a> {
+    r: Reader<'a>,
+    entries: Vec<DumpEntry>,
+}
+
+impl Walker<'_> {
+    fn push(
+        &mut self,
+        in_start: usize,
+        in_path: &str,
+        in_marker: Option<Marker>,
+        in_value: String,
+    ) {
+        self.entries.push(DumpEntry {
+            offset: in_start,
+            length: self.r.position() - in_start,
+            path: in_path.to_string(),
+            type_code: in_marker.map(Marker::to_u8),
+            value: in_value,
+        });
+    }
+
+    fn walk_payload(&mut self) -> Result<()> {
+        read_header(&mut self.r)?;
+        self.push(0, "", None, "storage header".to_string());
+
+        self.walk_section(PORTABLE_STORAGE_HEADER_SIZE, "")
+    }
+
+    // The entry of a field starts at its name
+    fn walk_section(&mut self, in_start: usize, in_path: &str) -> Result<()> {
+        self.r.enter()?;
+        self.r.add_object()?;
+        let number_fields = self.r.read_varint()?;
+        self.push(
+            in_start,
+            in_path,
+            Some(Marker::new(InnerMarker::Object)),
+            format!("{} fields", number_fields),
+        );
+
+        for _ in 0..number_fields {
+            let start = self.r.position();
+            let name = self.r.read_name()?;
+            let marker = Marker::try_from(self.r.read_u8()?)?;
+
+            let path = if in_path.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}.{}", in_path, name)
+            };
+            self.walk_value(start, &path, marker)?;
+        }
+        self.r.leave();
+
+        Ok(())
+    }
+
+    fn walk_value(&mut self, in_start: usize, in_path: &str, in_marker: Marker) -> Result<()> {
+        if in_marker.is_seq {
+            let length = self.r.read_array_length(in_marker)?;
+            self.push(
+                in_start,
+                in_path,
+                Some(in_marker),
+                format!("{} elements", length),
+            );
+
+            let marker = in_marker.into_scalar();
+            for i in 0..length {
+                let start = self.r.position();
+                self.walk_value(start, &format!("{}[{}]", in_path, i), marker)?;
+            }
+
+            return Ok(());
+        }
+
+        let value = match in_marker.inner {
+            InnerMarker::I64 => i64::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::I32 => i32::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::I16 => i16::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::I8 => i8::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::U64 => u64::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::U32 => u32::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::U16 => u16::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::U8 => self.r.read_u8()?.to_string(),
+            InnerMarker::F64 => f64::from_le_bytes(self.r.read_array()?).to_string(),
+            InnerMarker::Bool => (self.r.read_u8()? != 0).to_string(),
+            InnerMarker::String => {
+                let length = self.r.read_string_length()?;
+                format_string(self.r.read_bytes(length)?)
+            }
+            InnerMarker::Object => return self.walk_section(in_start, in_path),
+            // Element of an array of arrays, with its own marker
+            InnerMarker::Array => {
+                let marker = Marker::try_from(self.r.read_u8()?)?;
+                if !marker.is_seq {
+                    return Err(Error::Format("Array element is not an array"));
+                }
+
+                self.r.enter()?;
+                self.walk_value(in_start, in_path, marker)?;
+                self.r.leave();
+
+                return Ok(());
+            }
+        };
+
+        self.push(in_start, in_path, Some(in_marker), value);
+
+        Ok(())
+    }
+}
+
+// Text if it is printable, hex otherwise. Long strings are cut
+fn format_string(in_bytes: &[u8]) -> String {
+    let text = match std::str::from_utf8(in_bytes) {
+        Ok(t) if !t.chars().any(char::is_control) => format!("{:?}", t),
+        _ => hex::encode(in_bytes),
+    };
+
+    if text.len() > MAX_DUMP_VALUE {
+        let mut end = MAX_DUMP_VALUE;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}... ({} bytes)", &text[..end], in_bytes.len())
+    } else {
+        text
+    }
+}
+
+fn format_bytes(in_bytes: &[u8]) -> String {
+    if in_bytes.len() > MAX_DUMP_BYTES {
+        format!("{}..", hex::encode(&in_bytes[..MAX_DUMP_BYTES]))
+    } else {
+        hex::encode(in_bytes)
+    }
+}
+
+fn format_type(in_type_code: Option<u8>) -> String {
+    match in_type_code.map(|c| (c, Marker::try_from(c))) {
+        Some((c, Ok(m))) if m.is_seq => format!("{:#04x} {:?}[]", c, m.inner),
+        Some((c, Ok(m))) => format!("{:#04x} {:?}", c, m.inner),
+        Some((c, Err(_))) => format!("{:#04x}", c),
+        None => String::new(),
+    }
+}
+
+// One line per entry:
+//   offset  bytes  type  path = value
+impl fmt::Display for Dump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for e in &self.entries {
+            let bytes = &self.buffer[e.offset..e.offset + e.length];
+            let path = if e.path.is_empty() && e.type_code.is_some() {
+                "(root)"
+            } else {
+                &e.path
+            };
+
+            writeln!(
+                f,
+                "{:06x}  {:<26}  {:<13}  {} = {}",
+                e.offset,
+                format_bytes(bytes),
+                format_type(e.type_code),
+                path,
+                e.value
+            )?;
+        }
+
+        match &self.error {
+            Some((offset, e)) => writeln!(
+                f,
+                "{:06x}  ^^^ ERROR: {}. {} bytes not decoded",
+                offset,
+                e,
+                self.buffer.len() - offset
+            ),
+            None if self.end < self.buffer.len() => writeln!(
+                f,
+                "{:06x}  {} bytes after the root section",
+                self.end,
+                self.buffer.len() - self.end
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn epee_dump() {
+    #[derive(serde::Serialize)]
+    struct Address {
+        m_ip: u32,
+        m_port: u16,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Peer {
+        adr: Address,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Payload {
+        name: String,
+        peers: Vec<Peer>,
+    }
+
+    let payload = Payload {
+        name: "node".to_string(),
+        peers: vec![
+            Peer {
+                adr: Address {
+                    m_ip: 1,
+                    m_port: 18080,
+                },
+            },
+            Peer {
+                adr: Address {
+                    m_ip: 0x24f2f0b9,
+                    m_port: 18080,
+                },
+            },
+        ],
+    };
+    let bytes = crate::epee::to_bytes(&payload).unwrap();
+
+    let dump = annotate(&bytes, &DecodeLimits::default());
+    assert!(dump.error.is_none());
+    assert_eq!(bytes.len(), dump.end);
+
+    // The entries cover the whole payload
+    let length: usize = dump.entries.iter().map(|e| e.length).sum();
+    assert_eq!(bytes.len(), length);
+
+    let ip = dump
+        .entries
+        .iter()
+        .find(|e| e.path == "peers[1].adr.m_ip")
+        .unwrap();
+    assert_eq!(Some(6), ip.type_code);
+    assert_eq!("619901113", ip.value);
+    assert_eq!(
+        [0xb9, 0xf0, 0xf2, 0x24],
+        bytes[ip.offset + ip.length - 4..ip.offset + ip.length]
+    );
+
+    let name = &dump.entries[2];
+    assert_eq!(
+        ("name", "\"node\""),
+        (name.path.as_str(), name.value.as_str())
+    );
+
+    // The dump stops in the port of the second peer
+    let truncated = &bytes[..bytes.len() - 1];
+    let dump = annotate(truncated, &DecodeLimits::default());
+    let (offset, error) = dump.error.as_ref().unwrap();
+    assert!(matches!(error, Error::UnexpectedEof));
+    assert_eq!(bytes.len() - 2, *offset);
+    assert_eq!("peers[1].adr.m_ip", dump.entries.last().unwrap().path);
+    assert!(dump
+        .to_string()
+        .contains("^^^ ERROR: Unexpected end of data"));
+}
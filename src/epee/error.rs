@@ -0,0 +1,53 @@
+// Epee errors
+//
+
+use std::fmt::Display;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Invalid portable storage header")]
+    InvalidHeader,
+    #[error("Unexpected end of data")]
+    UnexpectedEof,
+    #[error("Unknown type code: {0:#x}")]
+    UnknownType(u8),
+    #[error("Maximum depth exceeded: {0}")]
+    DepthExceeded(usize),
+    #[error("Decode limit exceeded: {0}")]
+    LimitExceeded(&'static str),
+    #[error("Required field was not found: {0}")]
+    MissingField(&'static str),
+    #[error("Duplicate field: {0}")]
+    DuplicateField(&'static str),
+    #[error("Format error: {0}")]
+    Format(&'static str),
+    #[error("Value error: {0}")]
+    Value(&'static str),
+    // Errors of the Serialize and Deserialize implementations
+    #[error("{0}")]
+    Custom(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Error::MissingField(field)
+    }
+
+    fn duplicate_field(field: &'static str) -> Self {
+        Error::DuplicateField(field)
+    }
+}
@@ -0,0 +1,147 @@
+// Hashes
+//
+// Adapter for #[serde(with)]. monerod writes the hashes and the other
+// fixed size blobs as epee strings (KV_SERIALIZE_VAL_POD_AS_BLOB). In
+// JSON they are hex strings, although a list of bytes is accepted.
+// i.e.
+//
+//   #[serde(with = "crate::epee::hash")]
+//   pub top_id: [u8; 32],
+//
+
+use std::fmt;
+
+use serde::de::{self, SeqAccess, Unexpected, Visitor};
+use serde::{Deserializer, Serializer};
+
+pub fn serialize<S: Serializer, const N: usize>(
+    in_hash: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex::encode(in_hash))
+    } else {
+        serializer.serialize_bytes(in_hash)
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(HashVisitor)
+    } else {
+        deserializer.deserialize_bytes(HashVisitor)
+    }
+}
+
+struct HashVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for HashVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a blob of {} bytes", N)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        v.try_into().map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let bytes = hex::decode(v).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))?;
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut output = [0; N];
+        for (i, b) in output.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+
+        Ok(output)
+    }
+}
+
+// Optional blob. None is not written
+pub mod option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        in_hash: &Option<[u8; N]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        struct Hash<'a, const N: usize>(&'a [u8; N]);
+
+        impl<const N: usize> Serialize for Hash<'_, N> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(self.0, serializer)
+            }
+        }
+
+        match in_hash {
+            Some(h) => serializer.serialize_some(&Hash(h)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<Option<[u8; N]>, D::Error> {
+        #[derive(Deserialize)]
+        struct Hash<const N: usize>(#[serde(with = "super")] [u8; N]);
+
+        Ok(Option::<Hash<N>>::deserialize(deserializer)?.map(|h| h.0))
+    }
+}
+
+#[test]
+fn epee_hash() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Hashes {
+        #[serde(with = "crate::epee::hash")]
+        id: [u8; 4],
+        #[serde(default, with = "crate::epee::hash::option")]
+        other: Option<[u8; 2]>,
+        #[serde(with = "crate::epee::pod")]
+        list: Vec<[u8; 2]>,
+    }
+
+    let hashes = Hashes {
+        id: [1, 2, 3, 4],
+        other: None,
+        list: vec![[5, 6], [7, 8]],
+    };
+
+    // Strings of 4 and 2 * 2 bytes
+    let bytes = crate::epee::to_bytes(&hashes).unwrap();
+    assert_eq!(
+        [8, 2, b'i', b'd', 10, 16, 1, 2, 3, 4, 4, b'l', b'i', b's', b't', 10, 16, 5, 6, 7, 8],
+        bytes[9..]
+    );
+    assert_eq!(
+        hashes,
+        crate::epee::from_bytes(&bytes, &Default::default()).unwrap()
+    );
+
+    let json = serde_json::to_string(&hashes).unwrap();
+    assert_eq!(
+        r#"{"id":"01020304","other":null,"list":["0506","0708"]}"#,
+        json
+    );
+    assert_eq!(hashes, serde_json::from_str(&json).unwrap());
+    assert_eq!(
+        hashes,
+        serde_json::from_str(r#"{"id":[1,2,3,4],"list":["0506","0708"]}"#).unwrap()
+    );
+
+    // 3 bytes instead of 4
+    let mut bytes = bytes;
+    bytes[14] = 12;
+    assert!(crate::epee::from_bytes::<Hashes>(&bytes, &Default::default()).is_err());
+}
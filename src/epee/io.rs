@@ -0,0 +1,229 @@
+// Epee IO
+//
+// Reader over the received payload and the varint encoding of the
+// sizes. The two lowest bits of the first byte give the length of
+// the varint: 1, 2, 4 or 8 bytes
+//
+
+use bytes::BufMut;
+
+use crate::epee::error::{Error, Result};
+use crate::epee::limits::DecodeLimits;
+use crate::epee::marker::Marker;
+
+pub const PORTABLE_RAW_SIZE_MARK_MASK: u8 = 0x03;
+pub const PORTABLE_RAW_SIZE_MARK_BYTE: u8 = 0;
+pub const PORTABLE_RAW_SIZE_MARK_WORD: u8 = 1;
+pub const PORTABLE_RAW_SIZE_MARK_DWORD: u8 = 2;
+pub const PORTABLE_RAW_SIZE_MARK_INT64: u8 = 3;
+
+// Biggest value that fits in a varint
+pub const MAX_VARINT: u64 = u64::MAX >> 2;
+
+// Same value as EPEE_PORTABLE_STORAGE_RECURSION_LIMIT_INTERNAL in monerod
+pub const MAX_DEPTH: usize = 100;
+
+// Bytes of a payload. Nothing is read past the end and the sizes
+// given by the peer are checked against the limits
+#[derive(Debug)]
+pub struct Reader<'a> {
+    buffer: &'a [u8],
+    length: usize,
+    limits: DecodeLimits,
+    depth: usize,
+    objects: usize,
+    allocated: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(in_buffer: &'a [u8]) -> Self {
+        Reader::with_limits(in_buffer, &DecodeLimits::default())
+    }
+
+    pub fn with_limits(in_buffer: &'a [u8], in_limits: &DecodeLimits) -> Self {
+        Reader {
+            buffer: in_buffer,
+            length: in_buffer.len(),
+            limits: *in_limits,
+            depth: 0,
+            objects: 0,
+            allocated: 0,
+        }
+    }
+
+    pub fn limits(&self) -> &DecodeLimits {
+        &self.limits
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.buffer.len()
+    }
+
+    // Offset of the next byte from the start of the payload
+    pub fn position(&self) -> usize {
+        self.length - self.buffer.len()
+    }
+
+    pub fn read_bytes(&mut self, in_length: usize) -> Result<&'a [u8]> {
+        if self.buffer.len() < in_length {
+            return Err(Error::UnexpectedEof);
+        }
+
+        let (output, rest) = self.buffer.split_at(in_length);
+        self.buffer = rest;
+
+        Ok(output)
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut output = [0; N];
+        output.copy_from_slice(self.read_bytes(N)?);
+
+        Ok(output)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_varint(&mut self) -> Result<u64> {
+        let first = *self.buffer.first().ok_or(Error::UnexpectedEof)?;
+
+        let value = match first & PORTABLE_RAW_SIZE_MARK_MASK {
+            PORTABLE_RAW_SIZE_MARK_BYTE => u64::from(self.read_u8()?),
+            PORTABLE_RAW_SIZE_MARK_WORD => u64::from(u16::from_le_bytes(self.read_array()?)),
+            PORTABLE_RAW_SIZE_MARK_DWORD => u64::from(u32::from_le_bytes(self.read_array()?)),
+            _ => u64::from_le_bytes(self.read_array()?),
+        };
+
+        Ok(value >> 2)
+    }
+
+    // Sizes of strings and arrays, without checking the limits
+    pub fn read_size(&mut self) -> Result<usize> {
+        usize::try_from(self.read_varint()?).map_err(|_| Error::Value("Size too big"))
+    }
+
+    fn allocate(&mut self, in_bytes: usize) -> Result<()> {
+        self.allocated = self.allocated.saturating_add(in_bytes);
+        if self.allocated > self.limits.max_allocation {
+            return Err(Error::LimitExceeded("allocation"));
+        }
+
+        Ok(())
+    }
+
+    pub fn read_string_length(&mut self) -> Result<usize> {
+        let length = self.read_size()?;
+        if length > self.limits.max_string_length {
+            return Err(Error::LimitExceeded("string length"));
+        }
+        self.allocate(length)?;
+
+        Ok(length)
+    }
+
+    // The elements that are not numbers are counted as one byte. Their
+    // strings and sections are counted when they are read
+    pub fn read_array_length(&mut self, in_marker: Marker) -> Result<usize> {
+        let length = self.read_size()?;
+        if length > self.limits.max_array_length {
+            return Err(Error::LimitExceeded("array length"));
+        }
+        self.allocate(length.saturating_mul(in_marker.inner.size().unwrap_or(1)))?;
+
+        Ok(length)
+    }
+
+    // Name of a field: one byte with the length and the characters
+    pub fn read_name(&mut self) -> Result<&'a str> {
+        let length = self.read_u8()? as usize;
+        std::str::from_utf8(self.read_bytes(length)?)
+            .map_err(|_| Error::Format("Field name is not valid UTF-8"))
+    }
+
+    // Called before reading a section or an array inside an array
+    pub fn enter(&mut self) -> Result<()> {
+        if self.depth >= self.limits.max_depth {
+            return Err(Error::DepthExceeded(self.limits.max_depth));
+        }
+        self.depth += 1;
+
+        Ok(())
+    }
+
+    // Sections and arrays entered so far
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    // Called for every section, also for the skipped ones
+    pub fn add_object(&mut self) -> Result<()> {
+        self.objects += 1;
+        if self.objects > self.limits.max_objects {
+            return Err(Error::LimitExceeded("objects"));
+        }
+
+        Ok(())
+    }
+
+    pub fn leave(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+}
+
+pub fn write_varint<B: BufMut>(in_value: u64, w: &mut B) -> Result<()> {
+    if in_value > MAX_VARINT {
+        return Err(Error::Value("Size too big for a varint"));
+    }
+
+    if in_value < 1 << 6 {
+        w.put_u8((in_value as u8) << 2 | PORTABLE_RAW_SIZE_MARK_BYTE);
+    } else if in_value < 1 << 14 {
+        w.put_u16_le((in_value as u16) << 2 | PORTABLE_RAW_SIZE_MARK_WORD as u16);
+    } else if in_value < 1 << 30 {
+        w.put_u32_le((in_value as u32) << 2 | PORTABLE_RAW_SIZE_MARK_DWORD as u32);
+    } else {
+        w.put_u64_le(in_value << 2 | PORTABLE_RAW_SIZE_MARK_INT64 as u64);
+    }
+
+    Ok(())
+}
+
+pub fn write_name<B: BufMut>(in_name: &str, w: &mut B) -> Result<()> {
+    let length = u8::try_from(in_name.len()).map_err(|_| Error::Value("Field name too long"))?;
+    w.put_u8(length);
+    w.put_slice(in_name.as_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn epee_varint() {
+    for (value, length) in [
+        (0, 1),
+        (63, 1),
+        (64, 2),
+        (16383, 2),
+        (16384, 4),
+        (1 << 30, 8),
+        (MAX_VARINT, 8),
+    ] {
+        let mut buffer = Vec::new();
+        write_varint(value, &mut buffer).unwrap();
+        assert_eq!(length, buffer.len());
+
+        let mut r = Reader::new(&buffer);
+        assert_eq!(value, r.read_varint().unwrap());
+        assert_eq!(0, r.remaining());
+    }
+
+    // 250 elements, as the peer list of the handshake
+    assert_eq!(250, Reader::new(&[0xe9, 0x03]).read_varint().unwrap());
+
+    assert!(write_varint(MAX_VARINT + 1, &mut Vec::new()).is_err());
+    assert!(matches!(
+        Reader::new(&[0x02, 0x00]).read_varint(),
+        Err(Error::UnexpectedEof)
+    ));
+}
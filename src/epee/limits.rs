@@ -0,0 +1,106 @@
+// Decode limits
+//
+// The sizes in a payload come from the peer. These limits are checked
+// while the payload is read, so a malicious payload returns an error
+// instead of using all the memory or the stack
+//
+
+use crate::epee::io::MAX_DEPTH;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecodeLimits {
+    // Sections and arrays inside other sections and arrays
+    pub max_depth: usize,
+    // Sections in the whole payload
+    pub max_objects: usize,
+    // Elements of one array
+    pub max_array_length: usize,
+    // Bytes of one string
+    pub max_string_length: usize,
+    // Bytes of all the strings and arrays of the payload
+    pub max_allocation: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        DecodeLimits {
+            max_depth: MAX_DEPTH,
+            max_objects: 65536,
+            max_array_length: 65536,
+            // Same as the global packet limit of the codec
+            max_string_length: 100_000_000,
+            max_allocation: 100_000_000,
+        }
+    }
+}
+
+#[test]
+fn epee_decode_limits() {
+    use crate::epee::{from_bytes, to_bytes, Error, Section, Value};
+
+    // 3 levels: root, a and b
+    let mut b = Section::new();
+    b.insert("c", Value::String(b"xyz".to_vec()));
+    let mut a = Section::new();
+    a.insert("b", Value::Section(b));
+    a.insert("list", Value::Array(vec![Value::U64(1), Value::U64(2)]));
+    let mut root = Section::new();
+    root.insert("a", Value::Section(a));
+    let bytes = to_bytes(&root).unwrap();
+
+    let limits = DecodeLimits::default();
+    assert_eq!(root, from_bytes::<Section>(&bytes, &limits).unwrap());
+
+    let check = |limits: DecodeLimits| from_bytes::<Section>(&bytes, &limits).unwrap_err();
+    assert!(matches!(
+        check(DecodeLimits {
+            max_depth: 2,
+            ..limits
+        }),
+        Error::DepthExceeded(2)
+    ));
+    assert!(matches!(
+        check(DecodeLimits {
+            max_objects: 2,
+            ..limits
+        }),
+        Error::LimitExceeded("objects")
+    ));
+    assert!(matches!(
+        check(DecodeLimits {
+            max_array_length: 1,
+            ..limits
+        }),
+        Error::LimitExceeded("array length")
+    ));
+    assert!(matches!(
+        check(DecodeLimits {
+            max_string_length: 2,
+            ..limits
+        }),
+        Error::LimitExceeded("string length")
+    ));
+    // 2 * 8 bytes of the array and 3 of the string
+    assert!(matches!(
+        check(DecodeLimits {
+            max_allocation: 18,
+            ..limits
+        }),
+        Error::LimitExceeded("allocation")
+    ));
+
+    // An array that claims more elements than the payload has. Nothing
+    // is allocated for them
+    let mut bytes = bytes;
+    let name = bytes.windows(4).position(|w| w == b"list").unwrap();
+    bytes[name + 5..name + 9].copy_from_slice(&[0xfe, 0xff, 0xff, 0xff]);
+    let unlimited = DecodeLimits {
+        max_array_length: usize::MAX,
+        max_allocation: usize::MAX,
+        ..limits
+    };
+    assert!(matches!(
+        from_bytes::<Section>(&bytes, &unlimited),
+        Err(Error::UnexpectedEof)
+    ));
+}
@@ -0,0 +1,141 @@
+// Marker
+//
+// Type code written before every value. The array flag is set
+// when the value is a sequence of the inner type
+//
+
+use crate::epee::error::{Error, Result};
+
+pub const SERIALIZE_TYPE_INT64: u8 = 1;
+pub const SERIALIZE_TYPE_INT32: u8 = 2;
+pub const SERIALIZE_TYPE_INT16: u8 = 3;
+pub const SERIALIZE_TYPE_INT8: u8 = 4;
+pub const SERIALIZE_TYPE_UINT64: u8 = 5;
+pub const SERIALIZE_TYPE_UINT32: u8 = 6;
+pub const SERIALIZE_TYPE_UINT16: u8 = 7;
+pub const SERIALIZE_TYPE_UINT8: u8 = 8;
+pub const SERIALIZE_TYPE_DOUBLE: u8 = 9;
+pub const SERIALIZE_TYPE_STRING: u8 = 10;
+pub const SERIALIZE_TYPE_BOOL: u8 = 11;
+pub const SERIALIZE_TYPE_OBJECT: u8 = 12;
+pub const SERIALIZE_TYPE_ARRAY: u8 = 13;
+
+pub const SERIALIZE_FLAG_ARRAY: u8 = 0x80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InnerMarker {
+    I64,
+    I32,
+    I16,
+    I8,
+    U64,
+    U32,
+    U16,
+    U8,
+    F64,
+    String,
+    Bool,
+    Object,
+    // Element of an array of arrays. Every element has its own marker
+    Array,
+}
+
+impl InnerMarker {
+    pub fn to_u8(self) -> u8 {
+        match self {
+            InnerMarker::I64 => SERIALIZE_TYPE_INT64,
+            InnerMarker::I32 => SERIALIZE_TYPE_INT32,
+            InnerMarker::I16 => SERIALIZE_TYPE_INT16,
+            InnerMarker::I8 => SERIALIZE_TYPE_INT8,
+            InnerMarker::U64 => SERIALIZE_TYPE_UINT64,
+            InnerMarker::U32 => SERIALIZE_TYPE_UINT32,
+            InnerMarker::U16 => SERIALIZE_TYPE_UINT16,
+            InnerMarker::U8 => SERIALIZE_TYPE_UINT8,
+            InnerMarker::F64 => SERIALIZE_TYPE_DOUBLE,
+            InnerMarker::String => SERIALIZE_TYPE_STRING,
+            InnerMarker::Bool => SERIALIZE_TYPE_BOOL,
+            InnerMarker::Object => SERIALIZE_TYPE_OBJECT,
+            InnerMarker::Array => SERIALIZE_TYPE_ARRAY,
+        }
+    }
+
+    // Size of the fixed size types
+    pub fn size(self) -> Option<usize> {
+        match self {
+            InnerMarker::I64 | InnerMarker::U64 | InnerMarker::F64 => Some(8),
+            InnerMarker::I32 | InnerMarker::U32 => Some(4),
+            InnerMarker::I16 | InnerMarker::U16 => Some(2),
+            InnerMarker::I8 | InnerMarker::U8 | InnerMarker::Bool => Some(1),
+            InnerMarker::String | InnerMarker::Object | InnerMarker::Array => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for InnerMarker {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Ok(match value {
+            SERIALIZE_TYPE_INT64 => InnerMarker::I64,
+            SERIALIZE_TYPE_INT32 => InnerMarker::I32,
+            SERIALIZE_TYPE_INT16 => InnerMarker::I16,
+            SERIALIZE_TYPE_INT8 => InnerMarker::I8,
+            SERIALIZE_TYPE_UINT64 => InnerMarker::U64,
+            SERIALIZE_TYPE_UINT32 => InnerMarker::U32,
+            SERIALIZE_TYPE_UINT16 => InnerMarker::U16,
+            SERIALIZE_TYPE_UINT8 => InnerMarker::U8,
+            SERIALIZE_TYPE_DOUBLE => InnerMarker::F64,
+            SERIALIZE_TYPE_STRING => InnerMarker::String,
+            SERIALIZE_TYPE_BOOL => InnerMarker::Bool,
+            SERIALIZE_TYPE_OBJECT => InnerMarker::Object,
+            SERIALIZE_TYPE_ARRAY => InnerMarker::Array,
+            v => return Err(Error::UnknownType(v)),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    pub inner: InnerMarker,
+    pub is_seq: bool,
+}
+
+impl Marker {
+    pub const fn new(in_inner: InnerMarker) -> Self {
+        Marker {
+            inner: in_inner,
+            is_seq: false,
+        }
+    }
+
+    pub const fn into_seq(self) -> Self {
+        Marker {
+            inner: self.inner,
+            is_seq: true,
+        }
+    }
+
+    // Marker of the elements of a sequence
+    pub const fn into_scalar(self) -> Self {
+        Marker::new(self.inner)
+    }
+
+    pub fn to_u8(self) -> u8 {
+        if self.is_seq {
+            self.inner.to_u8() | SERIALIZE_FLAG_ARRAY
+        } else {
+            self.inner.to_u8()
+        }
+    }
+}
+
+impl TryFrom<u8> for Marker {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Ok(Marker {
+            inner: InnerMarker::try_from(value & !SERIALIZE_FLAG_ARRAY)?,
+            is_seq: value & SERIALIZE_FLAG_ARRAY != 0,
+        })
+    }
+}
@@ -0,0 +1,92 @@
+// Epee
+//
+// Portable storage format of monerod (contrib/epee). A payload is
+// the storage header followed by the root section. It replaces the
+// epee_encoding crate, which fails with the long handshake responses.
+// It is a serde data format, so the same derives are used for JSON
+// and epee. The optional fields of monerod (KV_SERIALIZE_OPT) are
+// written with:
+//
+//   #[serde(default, skip_serializing_if = "crate::epee::is_default")]
+//
+
+pub mod blob;
+pub mod de;
+pub mod dump;
+pub mod error;
+pub mod hash;
+pub mod io;
+pub mod limits;
+pub mod marker;
+pub mod pod;
+pub mod section;
+pub mod ser;
+pub mod stream;
+
+use serde::{Deserialize, Serialize};
+
+pub use de::{skip_epee_value, Deserializer};
+pub use dump::{annotate, Dump, DumpEntry};
+pub use error::{Error, Result};
+pub use io::Reader;
+pub use limits::DecodeLimits;
+pub use marker::{InnerMarker, Marker};
+pub use pod::{Hash, HashList, Pod, PodBlob};
+pub use section::{Section, Value};
+pub use ser::Serializer;
+pub use stream::{Event, StreamParser};
+
+pub const PORTABLE_STORAGE_SIGNATUREA: u32 = 0x01011101;
+pub const PORTABLE_STORAGE_SIGNATUREB: u32 = 0x01020101;
+pub const PORTABLE_STORAGE_FORMAT_VER: u8 = 1;
+
+pub const PORTABLE_STORAGE_HEADER_SIZE: usize = 9;
+
+pub fn read_header(r: &mut Reader) -> Result<()> {
+    let signature_a = u32::from_le_bytes(r.read_array()?);
+    let signature_b = u32::from_le_bytes(r.read_array()?);
+    let version = r.read_u8()?;
+
+    if signature_a != PORTABLE_STORAGE_SIGNATUREA
+        || signature_b != PORTABLE_STORAGE_SIGNATUREB
+        || version != PORTABLE_STORAGE_FORMAT_VER
+    {
+        return Err(Error::InvalidHeader);
+    }
+
+    Ok(())
+}
+
+pub fn write_header(w: &mut Vec<u8>) {
+    w.extend_from_slice(&PORTABLE_STORAGE_SIGNATUREA.to_le_bytes());
+    w.extend_from_slice(&PORTABLE_STORAGE_SIGNATUREB.to_le_bytes());
+    w.push(PORTABLE_STORAGE_FORMAT_VER);
+}
+
+// Decode a payload. The bytes after the root section are ignored,
+// i.e. the padding of a fragmented message
+pub fn from_bytes<'de, T: Deserialize<'de>>(
+    in_buffer: &'de [u8],
+    in_limits: &DecodeLimits,
+) -> Result<T> {
+    let mut r = Reader::with_limits(in_buffer, in_limits);
+    read_header(&mut r)?;
+
+    T::deserialize(Deserializer::new(&mut r, Marker::new(InnerMarker::Object)))
+}
+
+// The root must be a section, i.e. a struct or a map
+pub fn to_bytes<T: Serialize + ?Sized>(in_value: &T) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+    write_header(&mut output);
+
+    match in_value.serialize(Serializer::new(&mut output))? {
+        Some(m) if m == Marker::new(InnerMarker::Object) => Ok(output),
+        _ => Err(Error::Value("The root is not a section")),
+    }
+}
+
+// Optional fields are not written when they have the default value
+pub fn is_default<T: Default + PartialEq>(in_value: &T) -> bool {
+    *in_value == T::default()
+}
@@ -0,0 +1,256 @@
+// POD blobs
+//
+// Lists of values of fixed size that monerod writes as one string,
+// with the values one after the other (KV_SERIALIZE_CONTAINER_POD_AS_BLOB),
+// i.e. the hashes of NOTIFY_REQUEST_GET_OBJECTS or the block ids of
+// NOTIFY_RESPONSE_CHAIN_ENTRY. An empty list is not written, so the
+// fields need #[serde(default)]. The lists of hashes use:
+//
+//   #[serde(default, with = "crate::epee::pod")]
+//   pub blocks: Vec<[u8; 32]>,
+//
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::epee::hash;
+
+// Value that can be in a blob. In JSON, every value is written on its
+// own: the hashes in hex and the numbers as numbers
+pub trait Pod: Sized {
+    const SIZE: usize;
+
+    fn write_bytes(&self, out: &mut Vec<u8>);
+
+    // in_bytes has SIZE bytes
+    fn read_bytes(in_bytes: &[u8]) -> Self;
+
+    fn serialize_element<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_element<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl<const N: usize> Pod for [u8; N] {
+    const SIZE: usize = N;
+
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self);
+    }
+
+    fn read_bytes(in_bytes: &[u8]) -> Self {
+        let mut output = [0; N];
+        output.copy_from_slice(in_bytes);
+        output
+    }
+
+    fn serialize_element<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        hash::serialize(self, serializer)
+    }
+
+    fn deserialize_element<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        hash::deserialize(deserializer)
+    }
+}
+
+macro_rules! pod_numeric {
+    ($($ty:ty,)*) => {
+        $(
+            impl Pod for $ty {
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn write_bytes(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_bytes(in_bytes: &[u8]) -> Self {
+                    let mut output = [0; std::mem::size_of::<$ty>()];
+                    output.copy_from_slice(in_bytes);
+                    <$ty>::from_le_bytes(output)
+                }
+
+                fn serialize_element<S: Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    self.serialize(serializer)
+                }
+
+                fn deserialize_element<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    <$ty>::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+pod_numeric! {
+    u16,
+    u32,
+    u64,
+    i16,
+    i32,
+    i64,
+}
+
+pub type Hash = [u8; 32];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PodBlob<T>(pub Vec<T>);
+
+pub type HashList = PodBlob<Hash>;
+
+impl<T> PodBlob<T> {
+    pub fn new() -> Self {
+        PodBlob(Vec::new())
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for PodBlob<T> {
+    fn from(value: Vec<T>) -> Self {
+        PodBlob(value)
+    }
+}
+
+impl<T> Deref for PodBlob<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Pod> Serialize for PodBlob<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T: Pod> Deserialize<'de> for PodBlob<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(PodBlob)
+    }
+}
+
+// Adapter for #[serde(with)] of a Vec of POD values
+pub fn serialize<S: Serializer, T: Pod>(in_values: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        struct Element<'a, T>(&'a T);
+
+        impl<T: Pod> Serialize for Element<'_, T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize_element(serializer)
+            }
+        }
+
+        return serializer.collect_seq(in_values.iter().map(Element));
+    }
+
+    if in_values.is_empty() {
+        return serializer.serialize_none();
+    }
+
+    let mut output = Vec::with_capacity(in_values.len() * T::SIZE);
+    for v in in_values {
+        v.write_bytes(&mut output);
+    }
+
+    serializer.serialize_bytes(&output)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>, T: Pod>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_seq(PodBlobVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(PodBlobVisitor(PhantomData))
+    }
+}
+
+struct PodBlobVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Pod> Visitor<'de> for PodBlobVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a blob with values of {} bytes", T::SIZE)
+    }
+
+    // The length of the blob must be a multiple of the size
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if T::SIZE == 0 || !v.len().is_multiple_of(T::SIZE) {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        Ok(v.chunks_exact(T::SIZE).map(T::read_bytes).collect())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        struct Element<T>(T);
+
+        impl<'de, T: Pod> Deserialize<'de> for Element<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                T::deserialize_element(deserializer).map(Element)
+            }
+        }
+
+        let mut output = Vec::new();
+        while let Some(Element(v)) = seq.next_element::<Element<T>>()? {
+            output.push(v);
+        }
+
+        Ok(output)
+    }
+}
+
+#[test]
+fn epee_pod_blob() {
+    use crate::epee::{from_bytes, to_bytes, DecodeLimits};
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct ChainEntry {
+        #[serde(default)]
+        m_block_ids: HashList,
+        #[serde(default)]
+        m_block_weights: PodBlob<u64>,
+    }
+
+    let entry = ChainEntry {
+        m_block_ids: vec![[1; 32], [2; 32]].into(),
+        m_block_weights: vec![300, 70000].into(),
+    };
+
+    // One string of 64 bytes and one of 16
+    let bytes = to_bytes(&entry).unwrap();
+    assert_eq!(9 + 1 + (12 + 1 + 2 + 64) + (16 + 1 + 1 + 16), bytes.len());
+    assert_eq!(entry, from_bytes(&bytes, &DecodeLimits::default()).unwrap());
+
+    let json = serde_json::to_string(&entry.m_block_weights).unwrap();
+    assert_eq!("[300,70000]", json);
+    let json = serde_json::to_string(&entry.m_block_ids).unwrap();
+    assert_eq!(
+        format!(r#"["{}","{}"]"#, "01".repeat(32), "02".repeat(32)),
+        json
+    );
+    assert_eq!(entry.m_block_ids, serde_json::from_str(&json).unwrap());
+
+    // The empty lists are not written
+    let empty = ChainEntry::default();
+    let bytes = to_bytes(&empty).unwrap();
+    assert_eq!([0], bytes[9..]);
+    assert_eq!(empty, from_bytes(&bytes, &DecodeLimits::default()).unwrap());
+
+    // 33 bytes are not a list of hashes
+    let mut section = crate::epee::Section::new();
+    section.insert("m_block_ids", crate::epee::Value::String(vec![0; 33]));
+    let bytes = to_bytes(&section).unwrap();
+    assert!(from_bytes::<ChainEntry>(&bytes, &DecodeLimits::default()).is_err());
+}
@@ -0,0 +1,251 @@
+// Section
+//
+// Untyped portable storage. Any payload can be read without knowing
+// its fields, i.e. to log the messages that are not implemented.
+// The fields keep the order of the payload
+//
+
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    I64(i64),
+    I32(i32),
+    I16(i16),
+    I8(i8),
+    U64(u64),
+    U32(u32),
+    U16(u16),
+    U8(u8),
+    F64(f64),
+    // Binary blob. It is not always text
+    String(Vec<u8>),
+    Bool(bool),
+    Section(Section),
+    // All the elements have the same type
+    Array(Vec<Value>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Section {
+    entries: Vec<(String, Value)>,
+}
+
+impl Section {
+    pub fn new() -> Self {
+        Section::default()
+    }
+
+    pub fn get(&self, in_name: &str) -> Option<&Value> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name == in_name)
+            .map(|(_, v)| v)
+    }
+
+    // Replace the value if the field already exists
+    pub fn insert(&mut self, in_name: impl Into<String>, in_value: Value) {
+        let name = in_name.into();
+
+        match self.entries.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = in_value,
+            None => self.entries.push((name, in_value)),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.entries.iter().map(|(n, v)| (n.as_str(), v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// In JSON, the strings that are not printable text are written in
+// hex, i.e. hashes and network ids
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::I64(v) => serializer.serialize_i64(*v),
+            Value::I32(v) => serializer.serialize_i32(*v),
+            Value::I16(v) => serializer.serialize_i16(*v),
+            Value::I8(v) => serializer.serialize_i8(*v),
+            Value::U64(v) => serializer.serialize_u64(*v),
+            Value::U32(v) => serializer.serialize_u32(*v),
+            Value::U16(v) => serializer.serialize_u16(*v),
+            Value::U8(v) => serializer.serialize_u8(*v),
+            Value::F64(v) => serializer.serialize_f64(*v),
+            Value::String(v) if !serializer.is_human_readable() => serializer.serialize_bytes(v),
+            Value::String(v) => match std::str::from_utf8(v) {
+                Ok(s) if !s.chars().any(char::is_control) => serializer.serialize_str(s),
+                _ => serializer.serialize_str(&hex::encode(v)),
+            },
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::Section(v) => v.serialize(serializer),
+            Value::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for v in values {
+                    seq.serialize_element(v)?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+impl Serialize for Section {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+        for (name, value) in &self.entries {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+struct ValueVisitor;
+
+// Every type of epee has its own visit function, so the type is kept
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an epee value")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::I64(v))
+    }
+
+    fn visit_i32<E>(self, v: i32) -> Result<Value, E> {
+        Ok(Value::I32(v))
+    }
+
+    fn visit_i16<E>(self, v: i16) -> Result<Value, E> {
+        Ok(Value::I16(v))
+    }
+
+    fn visit_i8<E>(self, v: i8) -> Result<Value, E> {
+        Ok(Value::I8(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::U64(v))
+    }
+
+    fn visit_u32<E>(self, v: u32) -> Result<Value, E> {
+        Ok(Value::U32(v))
+    }
+
+    fn visit_u16<E>(self, v: u16) -> Result<Value, E> {
+        Ok(Value::U16(v))
+    }
+
+    fn visit_u8<E>(self, v: u8) -> Result<Value, E> {
+        Ok(Value::U8(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::F64(v))
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Value, E> {
+        Ok(Value::String(v.to_vec()))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::String(v.as_bytes().to_vec()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(v) = seq.next_element()? {
+            values.push(v);
+        }
+
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Value, A::Error> {
+        SectionVisitor.visit_map(map).map(Value::Section)
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct SectionVisitor;
+
+impl<'de> Visitor<'de> for SectionVisitor {
+    type Value = Section;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an epee section")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Section, A::Error> {
+        let mut section = Section::new();
+        while let Some((name, value)) = map.next_entry()? {
+            section.entries.push((name, value));
+        }
+
+        Ok(section)
+    }
+}
+
+impl<'de> Deserialize<'de> for Section {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(SectionVisitor)
+    }
+}
+
+#[test]
+fn epee_section() {
+    let mut address = Section::new();
+    address.insert("m_ip", Value::U32(0x0100007f));
+    address.insert("m_port", Value::U16(18080));
+
+    let mut root = Section::new();
+    root.insert("network_id", Value::String(vec![0x12, 0x30, 0xf1]));
+    root.insert("version", Value::String(b"0.18".to_vec()));
+    root.insert("addr", Value::Section(address));
+    root.insert(
+        "ids",
+        Value::Array(vec![Value::Array(vec![Value::U8(1)]), Value::Array(vec![])]),
+    );
+
+    let bytes = crate::epee::to_bytes(&root).unwrap();
+    let decoded: Section = crate::epee::from_bytes(&bytes, &Default::default()).unwrap();
+    assert_eq!(root, decoded);
+    assert_eq!(
+        Some(&Value::U16(18080)),
+        match decoded.get("addr") {
+            Some(Value::Section(s)) => s.get("m_port"),
+            _ => None,
+        }
+    );
+
+    assert_eq!(
+        r#"{"network_id":"1230f1","version":"0.18","addr":{"m_ip":16777343,"m_port":18080},"ids":[[1],[]]}"#,
+        serde_json::to_string(&decoded).unwrap()
+    );
+
+    let mut mixed = Section::new();
+    mixed.insert("a", Value::Array(vec![Value::U8(1), Value::U16(2)]));
+    assert!(crate::epee::to_bytes(&mixed).is_err());
+}
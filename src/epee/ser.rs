@@ -0,0 +1,388 @@
+// Epee serializer
+//
+// Serde data format of the portable storage. Every value returns its
+// marker, because the marker of a field is written before the value.
+// None and empty arrays are not written, as monerod does
+//
+
+use bytes::BufMut;
+use serde::ser::{self, Serialize};
+
+use crate::epee::error::{Error, Result};
+use crate::epee::io::{write_name, write_varint, Reader};
+use crate::epee::marker::{InnerMarker, Marker};
+
+// Writes a value. It returns None if nothing was written
+pub struct Serializer<'a> {
+    output: &'a mut Vec<u8>,
+}
+
+impl<'a> Serializer<'a> {
+    pub fn new(in_output: &'a mut Vec<u8>) -> Self {
+        Serializer { output: in_output }
+    }
+
+    fn write_string(self, in_value: &[u8]) -> Result<Option<Marker>> {
+        write_varint(in_value.len() as u64, self.output)?;
+        self.output.put_slice(in_value);
+
+        Ok(Some(Marker::new(InnerMarker::String)))
+    }
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+    type Ok = Option<Marker>;
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = ser::Impossible<Option<Marker>, Error>;
+    type SerializeMap = SectionSerializer<'a>;
+    type SerializeStruct = SectionSerializer<'a>;
+    type SerializeStructVariant = ser::Impossible<Option<Marker>, Error>;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok> {
+        self.output.put_u8(u8::from(v));
+        Ok(Some(Marker::new(InnerMarker::Bool)))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        self.output.put_i8(v);
+        Ok(Some(Marker::new(InnerMarker::I8)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        self.output.put_i16_le(v);
+        Ok(Some(Marker::new(InnerMarker::I16)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        self.output.put_i32_le(v);
+        Ok(Some(Marker::new(InnerMarker::I32)))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        self.output.put_i64_le(v);
+        Ok(Some(Marker::new(InnerMarker::I64)))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
+        self.output.put_u8(v);
+        Ok(Some(Marker::new(InnerMarker::U8)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
+        self.output.put_u16_le(v);
+        Ok(Some(Marker::new(InnerMarker::U16)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
+        self.output.put_u32_le(v);
+        Ok(Some(Marker::new(InnerMarker::U32)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
+        self.output.put_u64_le(v);
+        Ok(Some(Marker::new(InnerMarker::U64)))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok> {
+        self.output.put_f64_le(v);
+        Ok(Some(Marker::new(InnerMarker::F64)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok> {
+        self.write_string(v.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok> {
+        self.write_string(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok> {
+        self.write_string(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok> {
+        Ok(None)
+    }
+
+    // The variant is written as a string
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok> {
+        Err(Error::Value("Enums with values are not supported"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(SeqSerializer::new(self.output))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Ok(SeqSerializer::new(self.output))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Ok(SeqSerializer::new(self.output))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(Error::Value("Enums with values are not supported"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(SectionSerializer::new(self.output))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(SectionSerializer::new(self.output))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(Error::Value("Enums with values are not supported"))
+    }
+}
+
+// The elements are written after the number of elements, so they are
+// kept in a buffer until the end
+pub struct SeqSerializer<'a> {
+    output: &'a mut Vec<u8>,
+    buffer: Vec<u8>,
+    marker: Option<Marker>,
+    length: u64,
+}
+
+impl<'a> SeqSerializer<'a> {
+    fn new(in_output: &'a mut Vec<u8>) -> Self {
+        SeqSerializer {
+            output: in_output,
+            buffer: Vec::new(),
+            marker: None,
+            length: 0,
+        }
+    }
+
+    fn add_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let start = self.buffer.len();
+        let mut marker = value
+            .serialize(Serializer::new(&mut self.buffer))?
+            .ok_or(Error::Value("Array with empty elements"))?;
+
+        // The arrays inside an array have their own marker
+        if marker.is_seq {
+            self.buffer.insert(start, marker.to_u8());
+            marker = Marker::new(InnerMarker::Array);
+        }
+
+        match self.marker {
+            Some(m) if m != marker => {
+                return Err(Error::Value("Array with elements of different types"))
+            }
+            _ => self.marker = Some(marker),
+        }
+        self.length += 1;
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Option<Marker>> {
+        write_varint(self.length, self.output)?;
+        self.output.put_slice(&self.buffer);
+
+        // An empty array can have any type
+        let marker = self.marker.unwrap_or(Marker::new(InnerMarker::Object));
+        Ok(Some(marker.into_seq()))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = Option<Marker>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.add_element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer<'_> {
+    type Ok = Option<Marker>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.add_element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = Option<Marker>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.add_element(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+// Same as the arrays, the fields are written after the number of
+// fields. The name is removed again if the value is not written
+pub struct SectionSerializer<'a> {
+    output: &'a mut Vec<u8>,
+    buffer: Vec<u8>,
+    fields: u64,
+    key: Option<String>,
+}
+
+impl<'a> SectionSerializer<'a> {
+    fn new(in_output: &'a mut Vec<u8>) -> Self {
+        SectionSerializer {
+            output: in_output,
+            buffer: Vec::new(),
+            fields: 0,
+            key: None,
+        }
+    }
+
+    fn add_field<T: Serialize + ?Sized>(&mut self, in_name: &str, value: &T) -> Result<()> {
+        let start = self.buffer.len();
+        write_name(in_name, &mut self.buffer)?;
+        let marker_position = self.buffer.len();
+        self.buffer.put_u8(0);
+
+        match value.serialize(Serializer::new(&mut self.buffer))? {
+            // An empty array is only the varint 0
+            Some(m) if !(m.is_seq && self.buffer.len() == marker_position + 2) => {
+                self.buffer[marker_position] = m.to_u8();
+                self.fields += 1;
+            }
+            _ => self.buffer.truncate(start),
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Option<Marker>> {
+        write_varint(self.fields, self.output)?;
+        self.output.put_slice(&self.buffer);
+
+        Ok(Some(Marker::new(InnerMarker::Object)))
+    }
+}
+
+impl ser::SerializeMap for SectionSerializer<'_> {
+    type Ok = Option<Marker>;
+    type Error = Error;
+
+    // The key is written as a string to get its text
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        let mut buffer = Vec::new();
+        if key.serialize(Serializer::new(&mut buffer))? != Some(Marker::new(InnerMarker::String)) {
+            return Err(Error::Value("The name of a field must be a string"));
+        }
+
+        let mut r = Reader::new(&buffer);
+        let length = r.read_size()?;
+        let name = std::str::from_utf8(r.read_bytes(length)?)
+            .map_err(|_| Error::Value("Field name is not valid UTF-8"))?;
+        self.key = Some(name.to_string());
+
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or(Error::Value("Value of a field without name"))?;
+        self.add_field(&key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SectionSerializer<'_> {
+    type Ok = Option<Marker>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.add_field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
@@ -0,0 +1,386 @@
+// Stream parser
+//
+// Incremental parser of a payload. The bytes are fed as they are
+// received and the fields are returned as events, so a payload can
+// be checked before it is complete, i.e. the network_id of the
+// handshake response. A value is only returned once all its bytes
+// are received, but the sizes are checked against the limits as soon
+// as they are read
+//
+
+use crate::epee::error::{Error, Result};
+use crate::epee::io::Reader;
+use crate::epee::limits::DecodeLimits;
+use crate::epee::marker::{InnerMarker, Marker};
+use crate::epee::section::Value;
+use crate::epee::{read_header, PORTABLE_STORAGE_HEADER_SIZE};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    SectionStart,
+    SectionEnd,
+    // Name of the field whose value comes next
+    Key(String),
+    // Number of elements
+    ArrayStart(usize),
+    ArrayEnd,
+    // Numbers, strings and booleans. Sections and arrays are
+    // returned as the events of their fields and elements
+    Value(Value),
+}
+
+// Next thing to read from the payload
+#[derive(Debug)]
+enum Expect {
+    Header,
+    // Name of a field, element of an array or end of a container
+    Next,
+    Value(Marker),
+    // The length of the string was already read
+    StringBody(usize),
+    Done,
+}
+
+#[derive(Debug)]
+enum Frame {
+    // key is the name of the field being read
+    Section {
+        remaining: u64,
+        key: Option<String>,
+    },
+    // next is the index of the next element
+    Array {
+        marker: Marker,
+        length: usize,
+        next: usize,
+    },
+}
+
+#[derive(Debug)]
+pub struct StreamParser {
+    limits: DecodeLimits,
+    // Received bytes. The ones before start are already parsed
+    buffer: Vec<u8>,
+    start: usize,
+    // Bytes of the payload parsed before the start of the buffer
+    consumed: usize,
+    expect: Expect,
+    stack: Vec<Frame>,
+    objects: usize,
+    allocated: usize,
+}
+
+impl StreamParser {
+    pub fn new(in_limits: &DecodeLimits) -> Self {
+        StreamParser {
+            limits: *in_limits,
+            buffer: Vec::new(),
+            start: 0,
+            consumed: 0,
+            expect: Expect::Header,
+            stack: Vec::new(),
+            objects: 0,
+            allocated: 0,
+        }
+    }
+
+    // Add the next bytes of the payload. The parsed bytes are removed
+    // here and not after every event, so they are only moved once
+    pub fn feed(&mut self, in_bytes: &[u8]) {
+        if self.start > 0 {
+            self.buffer.drain(..self.start);
+            self.start = 0;
+        }
+        self.buffer.extend_from_slice(in_bytes);
+    }
+
+    // Bytes of the payload that were parsed
+    pub fn offset(&self) -> usize {
+        self.consumed
+    }
+
+    // The root section was read. The bytes after it are ignored
+    pub fn is_complete(&self) -> bool {
+        matches!(self.expect, Expect::Done)
+    }
+
+    // Path of the last event, i.e. local_peerlist_new[17].adr
+    pub fn path(&self) -> String {
+        let mut output = String::new();
+
+        for f in &self.stack {
+            match f {
+                Frame::Section { key: Some(k), .. } => {
+                    if !output.is_empty() {
+                        output.push('.');
+                    }
+                    output.push_str(k);
+                }
+                Frame::Array { next, .. } if *next > 0 => {
+                    output.push_str(&format!("[{}]", next - 1));
+                }
+                _ => {}
+            }
+        }
+
+        output
+    }
+
+    // Next event, or None if more bytes are needed or the payload is
+    // complete. After an error, the parser must not be used again
+    pub fn next_event(&mut self) -> Result<Option<Event>> {
+        loop {
+            let buffer = std::mem::take(&mut self.buffer);
+            let mut r = Reader::with_limits(&buffer[self.start..], &self.limits);
+            let result = self.step(&mut r);
+            // Nothing is consumed if the element is not complete
+            let position = if result.is_ok() { r.position() } else { 0 };
+
+            self.buffer = buffer;
+            self.start += position;
+            self.consumed += position;
+
+            let event = match result {
+                Ok(e) => e,
+                // Wait for more bytes
+                Err(Error::UnexpectedEof) => return Ok(None),
+                Err(e) => return Err(e),
+            };
+
+            match event {
+                Some(e) => return Ok(Some(e)),
+                None if self.is_complete() => return Ok(None),
+                None => {}
+            }
+        }
+    }
+
+    // Read one element of the payload. The state is only changed if
+    // all its bytes are available
+    fn step(&mut self, r: &mut Reader) -> Result<Option<Event>> {
+        match self.expect {
+            Expect::Header => {
+                if r.remaining() < PORTABLE_STORAGE_HEADER_SIZE {
+                    return Err(Error::UnexpectedEof);
+                }
+                read_header(r)?;
+                self.expect = Expect::Value(Marker::new(InnerMarker::Object));
+
+                Ok(None)
+            }
+            Expect::Next => self.read_next(r),
+            Expect::Value(marker) => self.read_value(r, marker),
+            Expect::StringBody(length) => {
+                let value = r.read_bytes(length)?.to_vec();
+                self.expect = Expect::Next;
+
+                Ok(Some(Event::Value(Value::String(value))))
+            }
+            Expect::Done => Ok(None),
+        }
+    }
+
+    fn read_next(&mut self, r: &mut Reader) -> Result<Option<Event>> {
+        let event = match self.stack.last_mut() {
+            None => {
+                self.expect = Expect::Done;
+                return Ok(None);
+            }
+            Some(Frame::Section { remaining: 0, .. }) => {
+                self.stack.pop();
+                Event::SectionEnd
+            }
+            Some(Frame::Section { remaining, key }) => {
+                let name = r.read_name()?;
+                let marker = Marker::try_from(r.read_u8()?)?;
+
+                *remaining -= 1;
+                *key = Some(name.to_string());
+                self.expect = Expect::Value(marker);
+                Event::Key(name.to_string())
+            }
+            Some(Frame::Array { length, next, .. }) if *next == *length => {
+                self.stack.pop();
+                Event::ArrayEnd
+            }
+            Some(Frame::Array { marker, next, .. }) => {
+                *next += 1;
+                self.expect = Expect::Value(*marker);
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(event))
+    }
+
+    fn read_value(&mut self, r: &mut Reader, in_marker: Marker) -> Result<Option<Event>> {
+        if in_marker.is_seq {
+            let length = r.read_size()?;
+            if length > self.limits.max_array_length {
+                return Err(Error::LimitExceeded("array length"));
+            }
+            self.allocate(length.saturating_mul(in_marker.inner.size().unwrap_or(1)))?;
+
+            self.push(Frame::Array {
+                marker: in_marker.into_scalar(),
+                length,
+                next: 0,
+            })?;
+            return Ok(Some(Event::ArrayStart(length)));
+        }
+
+        let value = match in_marker.inner {
+            InnerMarker::I64 => Value::I64(i64::from_le_bytes(r.read_array()?)),
+            InnerMarker::I32 => Value::I32(i32::from_le_bytes(r.read_array()?)),
+            InnerMarker::I16 => Value::I16(i16::from_le_bytes(r.read_array()?)),
+            InnerMarker::I8 => Value::I8(i8::from_le_bytes(r.read_array()?)),
+            InnerMarker::U64 => Value::U64(u64::from_le_bytes(r.read_array()?)),
+            InnerMarker::U32 => Value::U32(u32::from_le_bytes(r.read_array()?)),
+            InnerMarker::U16 => Value::U16(u16::from_le_bytes(r.read_array()?)),
+            InnerMarker::U8 => Value::U8(r.read_u8()?),
+            InnerMarker::F64 => Value::F64(f64::from_le_bytes(r.read_array()?)),
+            InnerMarker::Bool => Value::Bool(r.read_u8()? != 0),
+            // The body is read in the next step, so a long string
+            // is rejected before it is received
+            InnerMarker::String => {
+                let length = r.read_size()?;
+                if length > self.limits.max_string_length {
+                    return Err(Error::LimitExceeded("string length"));
+                }
+                self.allocate(length)?;
+                self.expect = Expect::StringBody(length);
+
+                return Ok(None);
+            }
+            InnerMarker::Object => {
+                let number_fields = r.read_varint()?;
+                self.objects += 1;
+                if self.objects > self.limits.max_objects {
+                    return Err(Error::LimitExceeded("objects"));
+                }
+
+                self.push(Frame::Section {
+                    remaining: number_fields,
+                    key: None,
+                })?;
+                return Ok(Some(Event::SectionStart));
+            }
+            // Element of an array of arrays, with its own marker
+            InnerMarker::Array => {
+                let marker = Marker::try_from(r.read_u8()?)?;
+                if !marker.is_seq {
+                    return Err(Error::Format("Array element is not an array"));
+                }
+                self.expect = Expect::Value(marker);
+
+                return Ok(None);
+            }
+        };
+        self.expect = Expect::Next;
+
+        Ok(Some(Event::Value(value)))
+    }
+
+    fn push(&mut self, in_frame: Frame) -> Result<()> {
+        if self.stack.len() >= self.limits.max_depth {
+            return Err(Error::DepthExceeded(self.limits.max_depth));
+        }
+        self.stack.push(in_frame);
+        self.expect = Expect::Next;
+
+        Ok(())
+    }
+
+    fn allocate(&mut self, in_bytes: usize) -> Result<()> {
+        self.allocated = self.allocated.saturating_add(in_bytes);
+        if self.allocated > self.limits.max_allocation {
+            return Err(Error::LimitExceeded("allocation"));
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn epee_stream_parser() {
+    #[derive(serde::Serialize)]
+    struct NodeData {
+        #[serde(with = "crate::epee::hash")]
+        network_id: [u8; 4],
+        peer_id: u64,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Payload {
+        list: Vec<Vec<u8>>,
+        node_data: NodeData,
+    }
+
+    let payload = Payload {
+        list: vec![vec![1, 2], vec![3]],
+        node_data: NodeData {
+            network_id: [0x12, 0x30, 0xf1, 0x71],
+            peer_id: 7,
+        },
+    };
+    let bytes = crate::epee::to_bytes(&payload).unwrap();
+
+    // One byte at a time, the events are the same as with all the bytes
+    let mut parser = StreamParser::new(&DecodeLimits::default());
+    let mut events = Vec::new();
+    for b in &bytes {
+        parser.feed(&[*b]);
+        while let Some(e) = parser.next_event().unwrap() {
+            events.push((parser.path(), e));
+        }
+    }
+    assert!(parser.is_complete());
+    assert_eq!(bytes.len(), parser.offset());
+
+    let mut parser = StreamParser::new(&DecodeLimits::default());
+    parser.feed(&bytes);
+    let mut all = Vec::new();
+    while let Some(e) = parser.next_event().unwrap() {
+        all.push((parser.path(), e));
+    }
+    assert_eq!(all, events);
+
+    assert_eq!((String::new(), Event::SectionStart), events[0]);
+    assert_eq!(
+        ("list[1][0]".to_string(), Event::Value(Value::U8(3))),
+        events[8]
+    );
+    assert_eq!(
+        ("node_data.peer_id".to_string(), Event::Value(Value::U64(7))),
+        events[16]
+    );
+    assert_eq!((String::new(), Event::SectionEnd), events[18]);
+
+    // The network id is checked before the rest of the payload arrives
+    let position = bytes.windows(4).position(|w| w == [0x12, 0x30, 0xf1, 0x71]);
+    let mut parser = StreamParser::new(&DecodeLimits::default());
+    parser.feed(&bytes[..position.unwrap() + 4]);
+    let mut network_id = None;
+    while let Some(e) = parser.next_event().unwrap() {
+        if let ("node_data.network_id", Event::Value(Value::String(v))) = (&*parser.path(), e) {
+            network_id = Some(v);
+        }
+    }
+    assert_eq!(Some(vec![0x12, 0x30, 0xf1, 0x71]), network_id);
+    assert!(!parser.is_complete());
+
+    // The length of a string is checked before its bytes arrive
+    let limits = DecodeLimits {
+        max_string_length: 3,
+        ..Default::default()
+    };
+    let mut parser = StreamParser::new(&limits);
+    parser.feed(&bytes[..position.unwrap()]);
+    let result = loop {
+        match parser.next_event() {
+            Ok(Some(_)) => {}
+            other => break other,
+        }
+    };
+    assert!(matches!(result, Err(Error::LimitExceeded("string length"))));
+}
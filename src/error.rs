@@ -0,0 +1,74 @@
+// Error
+//
+// Errors returned by the protocol and network modules, so the
+// callers can check the cause of a failure
+//
+
+use std::{io, time::Duration};
+
+use thiserror::Error;
+
+use crate::network::NetworkAddressTypeEnum;
+use crate::protocol::Command;
+
+#[derive(Debug, Error)]
+pub enum ConnectNodeError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    // Framing
+    #[error("Header too short: {0} bytes")]
+    HeaderTooShort(usize),
+    #[error("Invalid Levin signature: {0:x?}")]
+    InvalidSignature([u8; 8]),
+    #[error("Unsupported Levin version: {0}")]
+    InvalidVersion(u32),
+    #[error("Message of command {command} too long: {length} bytes. Maximum: {max}")]
+    MessageTooLong {
+        command: Command,
+        length: u64,
+        max: u64,
+    },
+    #[error("Connection closed by the node")]
+    ConnectionClosed,
+    #[error("Invalid fragment: {0}")]
+    InvalidFragment(&'static str),
+    #[error("Fragmented message too long: {length} bytes. Maximum: {max}")]
+    FragmentedMessageTooLong { length: u64, max: u64 },
+
+    // Payload
+    #[error("Epee encoding error: {0}")]
+    Epee(#[from] crate::epee::Error),
+    #[error("Config file error: {0}")]
+    Config(#[from] serde_json::Error),
+    #[error("Invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    // Handshake
+    #[error("Wrong network: {received:x?}. Expected: {expected:x?}")]
+    NetworkIdMismatch {
+        expected: [u8; 16],
+        received: [u8; 16],
+    },
+    #[error("Timeout waiting for command {command} after {timeout:?}")]
+    Timeout { command: Command, timeout: Duration },
+    #[error("Command {command} is not a valid {kind}")]
+    InvalidCommand {
+        command: Command,
+        kind: &'static str,
+    },
+    #[error("Unexpected response to command {command}")]
+    UnexpectedResponse { command: Command },
+    #[error("Ping failed with status: {0}")]
+    PingFailed(String),
+
+    // Transactions
+    #[error("Invalid transaction: {0}")]
+    InvalidTransaction(&'static str),
+
+    // Addresses
+    #[error("Unsupported address type: {0:?}")]
+    UnsupportedAddressType(NetworkAddressTypeEnum),
+    #[error("Invalid network address: {0}")]
+    InvalidAddress(&'static str),
+}
@@ -0,0 +1,290 @@
+// Fragment
+//
+// Fragmented Levin messages. A large message (header + payload) is
+// split in buckets of the same size. The first one has the B flag,
+// the last one the E flag and the payload of the last one is padded
+// with zeros. A bucket with both flags is just noise and it is
+// ignored.
+//
+
+use bytes::BytesMut;
+
+use crate::error::ConnectNodeError;
+use crate::protocol::{Command, Header, LevinFlags, P2PMessage, HEADER_SIZE};
+
+#[derive(Debug)]
+pub struct FragmentAssembler {
+    max_message_length: u64,
+    // Limit of the message being received. It is the one of the command
+    // of the begin fragment, and then the one of the inner header
+    max_length: u64,
+    inner_header_checked: bool,
+    // Header + payload of the fragmented message received so far
+    buffer: BytesMut,
+    in_progress: bool,
+}
+
+impl FragmentAssembler {
+    pub fn new(in_max_message_length: u64) -> Self {
+        FragmentAssembler {
+            max_message_length: in_max_message_length,
+            max_length: in_max_message_length,
+            inner_header_checked: false,
+            buffer: BytesMut::new(),
+            in_progress: false,
+        }
+    }
+
+    // Process a received bucket. It returns the message once it is
+    // complete, or None if more fragments are needed. in_max_length
+    // gives the limit of a command, so the size received so far is
+    // checked with every fragment and not only at the end
+    pub fn push(
+        &mut self,
+        in_bucket: P2PMessage,
+        in_max_length: impl Fn(Command) -> u64,
+    ) -> Result<Option<P2PMessage>, ConnectNodeError> {
+        let begin = in_bucket.header.flags.contains(LevinFlags::BEGIN);
+        let end = in_bucket.header.flags.contains(LevinFlags::END);
+
+        // Noise can be sent between the fragments of a message
+        if begin && end {
+            return Ok(None);
+        }
+
+        if !self.in_progress {
+            match (begin, end) {
+                // Normal message
+                (false, false) => return Ok(Some(in_bucket)),
+                (false, true) => {
+                    return Err(ConnectNodeError::InvalidFragment(
+                        "End fragment without begin fragment",
+                    ))
+                }
+                (true, _) => {
+                    self.buffer.clear();
+                    self.in_progress = true;
+                    self.max_length =
+                        in_max_length(in_bucket.header.command).min(self.max_message_length);
+                    self.inner_header_checked = false;
+                }
+            }
+        } else if begin {
+            self.reset();
+            return Err(ConnectNodeError::InvalidFragment(
+                "Begin fragment before the end of the previous message",
+            ));
+        }
+
+        let new_length = (self.buffer.len() + in_bucket.buffer.len()) as u64;
+        if new_length > self.max_length + HEADER_SIZE as u64 {
+            self.reset();
+            return Err(ConnectNodeError::FragmentedMessageTooLong {
+                length: new_length,
+                max: self.max_length,
+            });
+        }
+        self.buffer.extend_from_slice(&in_bucket.buffer);
+
+        // The limit of the inner command applies as soon as its header
+        // is received
+        if !self.inner_header_checked && self.buffer.len() >= HEADER_SIZE as usize {
+            let header = match Header::from_bytes(&self.buffer) {
+                Ok(h) => h,
+                Err(e) => {
+                    self.reset();
+                    return Err(e);
+                }
+            };
+            self.max_length = self.max_length.min(in_max_length(header.command));
+            self.inner_header_checked = true;
+
+            if header.msg_length > self.max_length {
+                self.reset();
+                return Err(ConnectNodeError::FragmentedMessageTooLong {
+                    length: header.msg_length,
+                    max: self.max_length,
+                });
+            }
+        }
+
+        if !end {
+            return Ok(None);
+        }
+
+        // The fragments contain a complete message, header included
+        self.in_progress = false;
+        let mut message_buffer = std::mem::take(&mut self.buffer);
+
+        let header = Header::from_bytes(&message_buffer)?;
+        let msg_length = header.msg_length;
+
+        if msg_length > self.max_length {
+            return Err(ConnectNodeError::FragmentedMessageTooLong {
+                length: msg_length,
+                max: self.max_length,
+            });
+        }
+        if message_buffer.len() < HEADER_SIZE as usize + msg_length as usize {
+            return Err(ConnectNodeError::InvalidFragment(
+                "Fragmented message shorter than its header length",
+            ));
+        }
+
+        // Remove the header and the padding
+        let _ = message_buffer.split_to(HEADER_SIZE as usize);
+        message_buffer.truncate(msg_length as usize);
+
+        Ok(Some(P2PMessage {
+            header,
+            buffer: message_buffer.freeze(),
+        }))
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.in_progress = false;
+    }
+}
+
+// Split a message in buckets of in_fragment_size bytes (header included),
+// as monerod does for the notifications. A message that fits in one
+// bucket is padded with zeros, the epee parser ignores them.
+pub fn split_message(
+    in_message: &P2PMessage,
+    in_fragment_size: usize,
+) -> Result<Vec<P2PMessage>, ConnectNodeError> {
+    if in_fragment_size < 2 * HEADER_SIZE as usize {
+        return Err(ConnectNodeError::InvalidFragment(
+            "Fragment size is smaller than two headers",
+        ));
+    }
+
+    let payload_space = in_fragment_size - HEADER_SIZE as usize;
+
+    if in_message.buffer.len() <= payload_space {
+        let mut padded_buffer = BytesMut::with_capacity(payload_space);
+        padded_buffer.extend_from_slice(&in_message.buffer);
+        padded_buffer.resize(payload_space, 0);
+
+        let mut padded = P2PMessage {
+            header: in_message.header.clone(),
+            buffer: padded_buffer.freeze(),
+        };
+        padded.header.msg_length = payload_space as u64;
+
+        return Ok(vec![padded]);
+    }
+
+    // The complete message is the payload of the fragments
+    let mut inner_header = in_message.header.clone();
+    inner_header.msg_length = in_message.buffer.len() as u64;
+
+    let inner_length = HEADER_SIZE as usize + in_message.buffer.len();
+    let number_fragments = inner_length.div_ceil(payload_space);
+
+    // Padded once, then every fragment is a slice of it
+    let mut inner_message = BytesMut::with_capacity(number_fragments * payload_space);
+    inner_header.write_to(&mut inner_message);
+    inner_message.extend_from_slice(&in_message.buffer);
+    inner_message.resize(number_fragments * payload_space, 0);
+    let inner_message = inner_message.freeze();
+
+    let mut output_fragments: Vec<P2PMessage> = Vec::new();
+
+    for i in 0..number_fragments {
+        let mut fragment = P2PMessage::new();
+
+        if i == 0 {
+            fragment.header.flags = LevinFlags::BEGIN;
+        } else if i == number_fragments - 1 {
+            fragment.header.flags = LevinFlags::END;
+        }
+
+        fragment.buffer = inner_message.slice(i * payload_space..(i + 1) * payload_space);
+        fragment.header.msg_length = payload_space as u64;
+
+        output_fragments.push(fragment);
+    }
+
+    Ok(output_fragments)
+}
+
+#[test]
+fn levin_fragments() {
+    let mut message = P2PMessage::new();
+    message.header.command = crate::protocol::Command::NewTransactions;
+    message.buffer = (0..1000).map(|i| i as u8).collect::<Vec<u8>>().into();
+    message.header.msg_length = message.buffer.len() as u64;
+
+    let fragments = split_message(&message, 256).unwrap();
+    assert_eq!(5, fragments.len());
+    assert_eq!(LevinFlags::BEGIN, fragments[0].header.flags);
+    assert!(fragments[2].header.flags.is_empty());
+    assert_eq!(LevinFlags::END, fragments[4].header.flags);
+
+    let mut assembler = FragmentAssembler::new(10_000);
+
+    // Noise is ignored
+    let mut noise = P2PMessage::new();
+    noise.header.flags = LevinFlags::BEGIN | LevinFlags::END;
+    assert!(assembler.push(noise, |_| 10_000).unwrap().is_none());
+
+    let mut output = None;
+    for f in fragments {
+        assert!(output.is_none());
+        output = assembler.push(f, |_| 10_000).unwrap();
+    }
+    let output = output.unwrap();
+    assert_eq!(message.header, output.header);
+    assert_eq!(message.buffer, output.buffer);
+
+    // Noise between the fragments does not change the message
+    let mut output = None;
+    for f in split_message(&message, 256).unwrap() {
+        assert!(output.is_none());
+        let mut noise = P2PMessage::new();
+        noise.header.flags = LevinFlags::BEGIN | LevinFlags::END;
+        noise.buffer = vec![0; 100].into();
+        assert!(assembler.push(noise, |_| 10_000).unwrap().is_none());
+        output = assembler.push(f, |_| 10_000).unwrap();
+    }
+    assert_eq!(message.buffer, output.unwrap().buffer);
+
+    // Limit of the reassembled message
+    let mut assembler = FragmentAssembler::new(500);
+    let fragments = split_message(&message, 256).unwrap();
+    let result: Result<Vec<_>, _> = fragments
+        .into_iter()
+        .map(|f| assembler.push(f, |_| 10_000))
+        .collect();
+    assert!(matches!(
+        result,
+        Err(ConnectNodeError::FragmentedMessageTooLong { .. })
+    ));
+
+    // The limit of the inner command is checked with the first fragment
+    let mut assembler = FragmentAssembler::new(10_000);
+    let mut fragments = split_message(&message, 256).unwrap().into_iter();
+    let result = assembler.push(fragments.next().unwrap(), |c| match c {
+        Command::NewTransactions => 500,
+        _ => 10_000,
+    });
+    assert!(matches!(
+        result,
+        Err(ConnectNodeError::FragmentedMessageTooLong { length: 1000, .. })
+    ));
+
+    // The size received so far is checked, even if the inner header
+    // gives a shorter length
+    let mut fragments = split_message(&message, 256).unwrap().into_iter();
+    let mut first = fragments.next().unwrap();
+    let mut buffer = first.buffer.to_vec();
+    buffer[8..16].copy_from_slice(&100u64.to_le_bytes());
+    first.buffer = buffer.into();
+    assert!(assembler.push(first, |_| 300).unwrap().is_none());
+    assert!(matches!(
+        assembler.push(fragments.next().unwrap(), |_| 300),
+        Err(ConnectNodeError::FragmentedMessageTooLong { .. })
+    ));
+}
@@ -0,0 +1,214 @@
+// Inspect
+//
+// Conversion of captured messages for debugging. A message can be
+// given as a hex string, raw bytes or a line of the log file, with
+// or without the Levin header
+//
+
+use bytes::Bytes;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::epee::{annotate, from_bytes, DecodeLimits, Section};
+use crate::error::ConnectNodeError;
+use crate::message::Message;
+use crate::protocol::{Command, Header, HEADER_SIZE, LEVIN_SIGNATURE};
+
+// Hex string. Spaces and a 0x prefix are allowed
+pub fn parse_hex(in_text: &str) -> Result<Vec<u8>, ConnectNodeError> {
+    let text: String = in_text.split_whitespace().collect();
+    let text = text.strip_prefix("0x").unwrap_or(&text);
+
+    hex::decode(text).map_err(|_| ConnectNodeError::InvalidInput("Invalid hex string"))
+}
+
+// Line of the log file with a buffer written with {:x?}, i.e.
+// "2024-06-30T22:19:39.238Z: [1, 11, 1, 1, ...]". If there are several
+// lists, the last one is the buffer
+pub fn parse_log_line(in_line: &str) -> Result<Vec<u8>, ConnectNodeError> {
+    let start = in_line
+        .rfind('[')
+        .ok_or(ConnectNodeError::InvalidInput("No list of bytes found"))?;
+    let length = in_line[start..]
+        .find(']')
+        .ok_or(ConnectNodeError::InvalidInput("List of bytes not closed"))?;
+
+    in_line[start + 1..start + length]
+        .split(',')
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+        .map(|b| {
+            u8::from_str_radix(b, 16).map_err(|_| ConnectNodeError::InvalidInput("Invalid byte"))
+        })
+        .collect()
+}
+
+fn to_json<T: Serialize>(in_value: &T) -> serde_json::Value {
+    serde_json::to_value(in_value).unwrap_or_default()
+}
+
+// Levin header, if present, and payload of a message
+fn split_header(in_bytes: &[u8]) -> Result<(Option<Header>, &[u8]), ConnectNodeError> {
+    if !in_bytes.starts_with(&LEVIN_SIGNATURE) {
+        return Ok((None, in_bytes));
+    }
+
+    let header = Header::from_bytes(in_bytes)?;
+    let mut body = &in_bytes[HEADER_SIZE as usize..];
+    // The capture can be truncated or have more messages after it
+    if (body.len() as u64) > header.msg_length {
+        body = &body[..header.msg_length as usize];
+    }
+
+    Ok((Some(header), body))
+}
+
+// Payload of the messages that are implemented
+fn decode_typed(
+    in_header: &Header,
+    in_body: &[u8],
+    in_limits: &DecodeLimits,
+) -> Result<Option<serde_json::Value>, ConnectNodeError> {
+    // A capture is small, it can be copied
+    let body = Bytes::copy_from_slice(in_body);
+    match Message::decode(in_header, &body, in_limits)? {
+        Message::Unknown { .. } => Ok(None),
+        m => Ok(Some(to_json(&m))),
+    }
+}
+
+// Decode a message, with or without the Levin header. The payload is
+// decoded with the type of the command, unless in_generic is set or
+// the command is not implemented. If the typed decoding fails, the
+// error and the generic payload are returned
+pub fn decode_message(
+    in_bytes: &[u8],
+    in_generic: bool,
+    in_limits: &DecodeLimits,
+) -> Result<serde_json::Value, ConnectNodeError> {
+    let mut output = serde_json::Map::new();
+    let (header, body) = split_header(in_bytes)?;
+
+    if let Some(h) = &header {
+        output.insert("header".to_string(), to_json(h));
+    }
+
+    if !in_generic {
+        if let Some(h) = &header {
+            match decode_typed(h, body, in_limits) {
+                Ok(Some(v)) => {
+                    output.insert("body".to_string(), v);
+                    return Ok(serde_json::Value::Object(output));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    output.insert("error".to_string(), json!(e.to_string()));
+                }
+            }
+        }
+    }
+
+    let section: Section = from_bytes(body, in_limits)?;
+    output.insert("body".to_string(), to_json(&section));
+
+    Ok(serde_json::Value::Object(output))
+}
+
+// Annotated hex dump of a message, with or without the Levin header.
+// The offsets are relative to the start of the payload
+pub fn dump_message(in_bytes: &[u8], in_limits: &DecodeLimits) -> Result<String, ConnectNodeError> {
+    let (header, body) = split_header(in_bytes)?;
+
+    let mut output = String::new();
+    if let Some(h) = header {
+        output.push_str(&format!("Levin {:?}\n", h));
+    }
+    output.push_str(&annotate(body, in_limits).to_string());
+
+    Ok(output)
+}
+
+// Levin message with the payload of in_json, i.e. handshake.json
+pub fn encode_message(
+    in_command: Command,
+    in_response: bool,
+    in_json: &str,
+) -> Result<Bytes, ConnectNodeError> {
+    let message = match (in_command, in_response) {
+        (Command::Handshake, false) => Message::HandshakeRequest(serde_json::from_str(in_json)?),
+        (Command::Handshake, true) => Message::HandshakeResponse(serde_json::from_str(in_json)?),
+        (Command::TimedSync, false) => Message::TimedSyncRequest(serde_json::from_str(in_json)?),
+        (Command::TimedSync, true) => Message::TimedSyncResponse(serde_json::from_str(in_json)?),
+        (Command::Ping, false) => Message::PingRequest(serde_json::from_str(in_json)?),
+        (Command::Ping, true) => Message::PingResponse(serde_json::from_str(in_json)?),
+        (Command::SupportFlags, false) => {
+            Message::SupportFlagsRequest(serde_json::from_str(in_json)?)
+        }
+        (Command::SupportFlags, true) => {
+            Message::SupportFlagsResponse(serde_json::from_str(in_json)?)
+        }
+        (Command::NewTransactions, false) => {
+            Message::NewTransactions(serde_json::from_str(in_json)?)
+        }
+        _ => {
+            return Err(ConnectNodeError::InvalidCommand {
+                command: in_command,
+                kind: "encodable message",
+            })
+        }
+    };
+
+    Ok(message.encode()?.to_bytes())
+}
+
+#[test]
+fn inspect_messages() {
+    let config = r#"{
+        "node_data": {
+            "network_id": "1230f171-6104-4161-1731-008216a1a111",
+            "my_port": 0, "rpc_port": 0, "rpc_credits_per_hash": 0,
+            "peer_id": 2271560481, "support_flags": 1
+        },
+        "payload_data": {
+            "current_height": 0, "cumulative_difficulty": 0,
+            "cumulative_difficulty_top64": 0, "top_id": [
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+            ],
+            "top_version": 0, "pruning_seed": 0
+        }
+    }"#;
+
+    let frame = encode_message(Command::Handshake, false, config).unwrap();
+    assert_eq!(HEADER_SIZE as usize + 248, frame.len());
+
+    // Same frame from hex and from the log
+    let log_line = format!("2024-06-30T22:19:39.225Z: {:x?}", &frame[..]);
+    assert_eq!(frame, parse_log_line(&log_line).unwrap());
+    assert_eq!(frame, parse_hex(&hex::encode(&frame)).unwrap());
+
+    let typed = decode_message(&frame, false, &DecodeLimits::default()).unwrap();
+    assert_eq!(1001, typed["header"]["command"]);
+    assert_eq!(2271560481_u64, typed["body"]["node_data"]["peer_id"]);
+    assert_eq!(
+        "1230f171-6104-4161-1731-008216a1a111",
+        typed["body"]["node_data"]["network_id"]
+    );
+
+    // Without the header
+    let generic = decode_message(
+        &frame[HEADER_SIZE as usize..],
+        false,
+        &DecodeLimits::default(),
+    )
+    .unwrap();
+    assert!(generic.get("header").is_none());
+    assert_eq!(
+        "1230f171610441611731008216a1a111",
+        generic["body"]["node_data"]["network_id"]
+    );
+
+    let dump = dump_message(&frame, &DecodeLimits::default()).unwrap();
+    assert!(dump.starts_with("Levin Header "));
+    assert!(dump.contains("node_data.peer_id = 2271560481"));
+}
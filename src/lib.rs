@@ -0,0 +1,17 @@
+// Connect Node
+//
+// Levin protocol messages, framing and network types used
+// by the connect_node client
+//
+
+pub mod codec;
+pub mod dispatcher;
+pub mod epee;
+pub mod error;
+pub mod fragment;
+pub mod inspect;
+pub mod message;
+pub mod network;
+pub mod node;
+pub mod protocol;
+pub mod transaction;
@@ -5,37 +5,46 @@
  *               the handshake. After that, it dumps all received
  *               messages to a log file
  *
- *  NOTE: It does not work because the Monero P2P uses Boosts for
+ *  NOTE: The Monero P2P uses the epee portable storage for
  * serializing the messages and it is mainly undocumented.
- * The crate I have used 'epee_encoding' does not work with long
- * messages.
+ * The crate I used first, 'epee_encoding', does not work with long
+ * messages, so they are encoded with the epee module of this crate.
  *
  */
 use std::{
     fs::File,
-    io::{ErrorKind, Write},
-    net::Ipv4Addr,
-    sync::{Arc, Mutex},
-    thread::sleep,
-    time::Duration,
+    io::Write,
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
 };
 
-use clap::{arg, value_parser, Command};
+use clap::{arg, value_parser, ArgGroup, ArgMatches, Command};
 
 use chrono::prelude::*;
-use epee_encoding::{from_bytes, to_bytes};
-use protocol::{
-    HandshakeRequest, HandshakeResponse, Header, P2PMessage, PayloadType, PeerListEntryBase,
-    HANDSHAKE_REQUEST, HEADER_SIZE, NETWORK_STATE_REQUEST, PEER_ID_REQUEST, PING_REQUEST,
-    STAT_INFO_REQUEST, SUPPORT_FLAGS_REQUEST, TIMED_SYNC_REQUEST,
+use connect_node::codec::{LevinCodec, NetworkIdCheck};
+use connect_node::dispatcher::Dispatcher;
+use connect_node::epee::{annotate, from_bytes, to_bytes, DecodeLimits, Section};
+use connect_node::error::ConnectNodeError;
+use connect_node::inspect::{
+    decode_message, dump_message, encode_message, parse_hex, parse_log_line,
 };
-use tokio::{
-    io::{self, AsyncReadExt, AsyncWriteExt},
-    net::TcpStream,
+use connect_node::message::Message;
+use connect_node::node::{ping, request_support_flags, NodeState, PING_TIMEOUT};
+use connect_node::protocol::{
+    Command as LevinCommand, HandshakeRequest, HandshakeResponse, P2PMessage, PayloadType,
+    PeerListEntryBase, SupportFlags,
 };
+use connect_node::transaction::tx_hash;
+use futures::{SinkExt, Stream, StreamExt};
+use tokio::{io::AsyncWriteExt, net::TcpStream, sync::mpsc, time::timeout};
+use tokio_util::codec::{FramedRead, FramedWrite};
 
-mod network;
-mod protocol;
+// Same value as P2P_DEFAULT_HANDSHAKE_INVOKE_TIMEOUT in monerod
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_millis(5000);
 
 fn write_log(in_file: &Option<File>, in_message: impl AsRef<str>) {
     let now = Utc::now();
@@ -52,37 +61,32 @@ fn write_log(in_file: &Option<File>, in_message: impl AsRef<str>) {
     }
 }
 
-async fn read_message(
+async fn read_message<S>(
     in_log_file: &Option<File>,
-    in_connection: &mut TcpStream,
-) -> Result<P2PMessage, io::Error> {
-    let mut output_message: P2PMessage = P2PMessage::new();
-
-    let mut response_msg_header_buffer: [u8; HEADER_SIZE as usize] = [0; HEADER_SIZE as usize];
-    let mut bytes_read = match in_connection
-        .read_exact(&mut response_msg_header_buffer)
-        .await
-    {
-        Ok(b) => b,
-        Err(e) => {
-            write_log(in_log_file, format!("ERROR: Reading handshake header.{e}"));
+    in_connection: &mut S,
+    in_limits: &DecodeLimits,
+    in_dump: bool,
+) -> Result<P2PMessage, ConnectNodeError>
+where
+    S: Stream<Item = Result<P2PMessage, ConnectNodeError>> + Unpin,
+{
+    // The codec only returns complete messages
+    let output_message: P2PMessage = match in_connection.next().await {
+        Some(Ok(m)) => m,
+        Some(Err(e)) => {
+            write_log(in_log_file, format!("ERROR: Reading message. {e}"));
             return Err(e);
         }
+        None => {
+            write_log(in_log_file, "ERROR: Connection closed by the node");
+            return Err(ConnectNodeError::ConnectionClosed);
+        }
     };
-    write_log(in_log_file, format!("Bytes read: {}", bytes_read));
-
-    // Process the header
-    let mut response_header: Header = Header::new();
-    response_header.from_bytes(&response_msg_header_buffer);
 
     write_log(
         in_log_file,
-        format!("Response header {:?}", response_header),
+        format!("Response header {:?}", output_message.header),
     );
-
-    output_message.header = response_header;
-
-    // Read the rest of the message
     write_log(
         in_log_file,
         format!(
@@ -90,44 +94,63 @@ async fn read_message(
             output_message.header.msg_length
         ),
     );
-    if output_message.header.msg_length <= 0 {
-        write_log(
-            in_log_file,
-            format!(
-                "ERROR: Reading handshake responser header.Incorrect length: {}",
-                output_message.header.msg_length
-            ),
-        );
-        return Err(io::Error::new(
-            ErrorKind::UnexpectedEof,
-            "Message length is zero",
-        ));
-    }
-    let mut response_p2p_msg_buffer: Vec<u8> = Vec::new();
+    write_log(in_log_file, format!("{:x?}", &output_message.buffer[..]));
+    log_payload(in_log_file, &output_message, in_limits, in_dump);
 
-    response_p2p_msg_buffer.resize(output_message.header.msg_length as usize, 0);
-
-    bytes_read = in_connection.read(&mut response_p2p_msg_buffer).await?;
-    write_log(in_log_file, format!("{:x?}", response_p2p_msg_buffer));
-    // println!("{}", response_p2p_msg_buffer.by_ref().escape_ascii());
+    Ok(output_message)
+}
 
-    output_message.buffer = response_p2p_msg_buffer;
+// Log the fields of any payload as JSON, even if the command is not
+// implemented. The annotated dump is logged if in_dump is set or the
+// payload cannot be decoded
+fn log_payload(
+    in_log_file: &Option<File>,
+    in_message: &P2PMessage,
+    in_limits: &DecodeLimits,
+    in_dump: bool,
+) {
+    match from_bytes::<Section>(&in_message.buffer, in_limits) {
+        Ok(s) => {
+            write_log(
+                in_log_file,
+                format!(
+                    "Payload: {}",
+                    serde_json::to_string_pretty(&s).unwrap_or_default()
+                ),
+            );
+            if in_dump {
+                log_dump(in_log_file, &in_message.buffer, in_limits);
+            }
+        }
+        Err(e) => {
+            write_log(in_log_file, format!("ERROR: Decoding payload: {}", e));
+            log_dump(in_log_file, &in_message.buffer, in_limits);
+        }
+    }
+}
 
-    Ok(output_message)
+fn log_dump(in_log_file: &Option<File>, in_buffer: &[u8], in_limits: &DecodeLimits) {
+    write_log(
+        in_log_file,
+        format!("Payload dump:\n{}", annotate(in_buffer, in_limits)),
+    );
 }
 
-async fn do_handshake(
+// Handshake request of the config file, or the default one
+fn build_request(
     in_config_file: Option<String>,
     in_log_file: &Option<File>,
-    in_connection: &mut TcpStream,
-) -> Result<(), io::Error> {
+) -> Result<HandshakeRequest, ConnectNodeError> {
     // Create request
     let mut request: HandshakeRequest = HandshakeRequest::new();
 
     // Read from a config file, if so
     match in_config_file {
         Some(c) => {
-            request.load_from_file(c);
+            if let Err(e) = request.load_from_file(c) {
+                write_log(in_log_file, format!("ERROR: Reading config file: {}", e));
+                return Err(e);
+            }
         }
         None => {
             // Set Node data
@@ -138,84 +161,130 @@ async fn do_handshake(
         }
     }
 
+    Ok(request)
+}
+
+// The handshake is completed, so the longer messages are accepted, as
+// soon as the response is checked. It returns the flags of the node
+async fn do_handshake(
+    in_request: &HandshakeRequest,
+    in_log_file: &Option<File>,
+    in_dispatcher: &Dispatcher,
+    in_node: &Mutex<NodeState>,
+    in_limits: &DecodeLimits,
+    out_handshake_completed: &AtomicBool,
+) -> Result<SupportFlags, ConnectNodeError> {
     // Serialize the request
-    let request_msg_buffer = match to_bytes(&request) {
+    let request_msg_buffer = match to_bytes(in_request) {
         Ok(m) => m,
         Err(e) => {
             write_log(
                 in_log_file,
                 format!("ERROR: Encoding Handshake request: {}", e),
             );
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "ERROR: Serializing request",
-            ));
+            return Err(e.into());
         }
     };
 
-    // Create Handshake request message
-    let mut request_p2p_message = P2PMessage::new_command(HANDSHAKE_REQUEST);
-
-    // Set the message length and encode as byte array
-    request_p2p_message.header.msg_length = request_msg_buffer.len() as u64;
-    request_p2p_message.buffer = request_msg_buffer;
-
-    let p2p_message_buffer = request_p2p_message.to_bytes();
-
     write_log(
         in_log_file,
         format!(
             "Sending request: {} {:x?}",
-            request_p2p_message.header.msg_length, request_p2p_message
+            request_msg_buffer.len(),
+            request_msg_buffer
         ),
     );
-    write_log(in_log_file, format!("{:x?}", p2p_message_buffer));
-
-    // Send Handshake request
-    in_connection.write_all(&p2p_message_buffer).await?;
 
-    // Read reply message
-    let received_p2p_message: P2PMessage = read_message(in_log_file, in_connection).await?;
+    // Send Handshake request and wait for the reply message
+    let received_p2p_message: P2PMessage = match in_dispatcher
+        .invoke(LevinCommand::Handshake, request_msg_buffer)
+        .await
+    {
+        Ok(m) => m,
+        Err(e) => {
+            write_log(
+                in_log_file,
+                format!("ERROR: Waiting for Handshake response: {}", e),
+            );
+            return Err(e);
+        }
+    };
 
     write_log(
         in_log_file,
         format!("Buffer len: {}", received_p2p_message.buffer.len()),
     );
-    let response: HandshakeResponse = match from_bytes(&received_p2p_message.buffer) {
+    let response: HandshakeResponse = match from_bytes(&received_p2p_message.buffer, in_limits) {
         Ok(r) => r,
         Err(e) => {
             write_log(
                 in_log_file,
                 format!("ERROR: Decoding Handshake response: {}", e),
             );
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "ERROR: Deserializing request",
-            ));
+            log_dump(in_log_file, &received_p2p_message.buffer, in_limits);
+            return Err(e.into());
         }
     };
 
-    // Check parameters
-    if response.node_data.network_id != request.node_data.network_id {
-        write_log(
-            in_log_file,
-            format!("ERROR: Wrong network: {:x?}", response.node_data.network_id),
-        );
-        return Err(io::Error::new(io::ErrorKind::Other, "ERROR: Wrong network"));
-    }
+    // The network id was checked by the codec, a response of another
+    // network fails the invoke
+    out_handshake_completed.store(true, Ordering::Release);
 
     // Read the list of peer entries
-    let _list_peers = read_peer_list(in_log_file, &response);
+    let list_peers = read_peer_list(in_log_file, &response);
+
+    // They are sent in the timed syncs
+    {
+        let mut node = in_node.lock().unwrap();
+        node.add_peers(list_peers.into_iter().cloned());
+        node.set_remote_payload_data(response.payload_data.clone());
+    }
 
-    process_payload_data(in_log_file, &response.payload_data).unwrap();
+    process_payload_data(in_log_file, &response.payload_data)?;
 
-    Ok(())
+    Ok(response.node_data.support_flags)
+}
+
+// Empty flags are asked after the handshake, as monerod does. The
+// connection is kept if the node does not answer
+async fn negotiate_support_flags(
+    in_flags: SupportFlags,
+    in_log_file: &Option<File>,
+    in_dispatcher: &Dispatcher,
+    in_node: &Mutex<NodeState>,
+    in_limits: &DecodeLimits,
+) {
+    let mut remote_flags = Some(in_flags);
+    if in_flags.is_empty() {
+        remote_flags = match request_support_flags(in_dispatcher, in_limits).await {
+            Ok(f) => Some(f),
+            Err(e) => {
+                write_log(
+                    in_log_file,
+                    format!("ERROR: Requesting Support Flags: {}", e),
+                );
+                None
+            }
+        };
+    }
+    if let Some(f) = remote_flags {
+        let mut node = in_node.lock().unwrap();
+        node.set_remote_support_flags(f);
+        write_log(
+            in_log_file,
+            format!(
+                "Support flags: {:?}. Negotiated: {:?}",
+                f,
+                node.negotiated_flags()
+            ),
+        );
+    }
 }
 
 fn process_payload_data(
     in_log_file: &Option<File>,
     in_data: &PayloadType,
-) -> Result<(), io::Error> {
+) -> Result<(), ConnectNodeError> {
     write_log(
         in_log_file,
         format!("Processing payload data: {:x?}", in_data),
@@ -223,72 +292,354 @@ fn process_payload_data(
     Ok(())
 }
 
-fn read_peer_list(
+// Only the peers that can be dialed are kept
+fn read_peer_list<'a>(
     in_log_file: &Option<File>,
-    _in_response: &HandshakeResponse,
-) -> Vec<PeerListEntryBase> {
-    let output_list: Vec<PeerListEntryBase> = Vec::new();
+    in_response: &'a HandshakeResponse,
+) -> Vec<&'a PeerListEntryBase> {
+    write_log(in_log_file, "Reading peer list");
 
-    write_log(in_log_file, format!("Reading peer list"));
+    let output_list: Vec<&PeerListEntryBase> = in_response.routable_peers().collect();
+
+    write_log(
+        in_log_file,
+        format!(
+            "Routable peers: {} of {}",
+            output_list.len(),
+            in_response.local_peerlist_new.len()
+        ),
+    );
 
     output_list
 }
 
 async fn process_message(
-    in_log_file: Arc<Mutex<Option<File>>>,
+    in_log_file: &Option<File>,
     in_message: P2PMessage,
-    out_end_flag: Arc<Mutex<bool>>,
+    in_dispatcher: &Dispatcher,
+    in_node: &Mutex<NodeState>,
+    in_limits: &DecodeLimits,
 ) {
-    match in_message.header.command {
-        HANDSHAKE_REQUEST => {
-            let f = in_log_file.lock().unwrap();
-            write_log(&f, format!("Reply to Handshake request"));
+    let message = match Message::from_p2p(&in_message, in_limits) {
+        Ok(m) => m,
+        Err(e) => {
+            write_log(
+                in_log_file,
+                format!("ERROR: Decoding {}: {}", in_message.header.command, e),
+            );
+            return;
         }
+    };
+
+    let response = match message {
+        Message::TimedSyncRequest(r) => {
+            write_log(in_log_file, "Reply to Time sync request");
 
-        TIMED_SYNC_REQUEST => {
-            let f = in_log_file.lock().unwrap();
-            write_log(&f, format!("Reply to Time sync request"));
+            // monerod drops the connection if it is not answered
+            let mut node = in_node.lock().unwrap();
+            write_log(
+                in_log_file,
+                format!("Node height: {}", r.payload_data.current_height),
+            );
+            node.answer_timed_sync(&r)
         }
 
-        PING_REQUEST => {
-            let f = in_log_file.lock().unwrap();
-            write_log(&f, format!("Reply to Ping request"));
+        Message::PingRequest(_) => {
+            write_log(in_log_file, "Reply to Ping request");
+            in_node.lock().unwrap().answer_ping()
         }
-        STAT_INFO_REQUEST => {
-            let f = in_log_file.lock().unwrap();
-            write_log(&f, format!("Reply to Info request"));
+
+        Message::SupportFlagsRequest(_) => {
+            write_log(in_log_file, "Reply to Support Flags request");
+            in_node.lock().unwrap().answer_support_flags()
         }
 
-        NETWORK_STATE_REQUEST => {
-            let f = in_log_file.lock().unwrap();
-            write_log(&f, format!("Reply to Network State request"));
+        // Notification, there is no response
+        Message::NewTransactions(n) => {
+            let phase = if n.dandelionpp_fluff { "fluff" } else { "stem" };
+            write_log(
+                in_log_file,
+                format!("New transactions: {} ({})", n.txs.len(), phase),
+            );
+            for tx in &n.txs {
+                let text = match tx_hash(tx) {
+                    Ok(h) => format!("  Transaction {} {} bytes", hex::encode(h), tx.len()),
+                    Err(e) => format!("  ERROR: Transaction of {} bytes: {}", tx.len(), e),
+                };
+                write_log(in_log_file, text);
+            }
+            return;
         }
 
-        PEER_ID_REQUEST => {
-            let f = in_log_file.lock().unwrap();
-            write_log(&f, format!("Reply to Peer ID request"));
+        // The session goes on, so the traffic of the node can be
+        // followed in the log, where read_message wrote the payload
+        Message::Unknown { command, .. } => {
+            write_log(in_log_file, format!("Unsupported command: {}", command));
+            return;
         }
 
-        SUPPORT_FLAGS_REQUEST => {
-            let f = in_log_file.lock().unwrap();
-            write_log(&f, format!("Reply to Support Flags request"));
+        // Requests of a second handshake and responses nobody waits for
+        m => {
+            write_log(in_log_file, format!("Ignoring message: {}", m.command()));
+            return;
         }
-        _ => {
-            let f = in_log_file.lock().unwrap();
-            write_log(
-                &f,
-                format!("ERROR: Unsupported command: {}", in_message.header.command),
+    };
+
+    let result = match response {
+        Ok(r) => in_dispatcher.send(r).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        write_log(
+            in_log_file,
+            format!(
+                "ERROR: Sending {} response: {}",
+                in_message.header.command, e
+            ),
+        );
+    }
+}
+
+// Read messages until the connection is closed. The responses are
+// delivered to the requests waiting in the dispatcher
+async fn read_messages<S>(
+    in_log_file: Arc<Option<File>>,
+    mut in_connection: S,
+    in_dispatcher: Dispatcher,
+    in_node: Arc<Mutex<NodeState>>,
+    in_limits: DecodeLimits,
+    in_dump: bool,
+    in_end_flag: Arc<Mutex<bool>>,
+) where
+    S: Stream<Item = Result<P2PMessage, ConnectNodeError>> + Unpin,
+{
+    let mut reader_error = ConnectNodeError::ConnectionClosed;
+
+    while !*in_end_flag.lock().unwrap() {
+        let input_message: P2PMessage =
+            match read_message(&in_log_file, &mut in_connection, &in_limits, in_dump).await {
+                Ok(m) => m,
+                Err(e) => {
+                    // The stream cannot be resynchronised after a framing error,
+                    // i.e. a message longer than the limits
+                    write_log(
+                        &in_log_file,
+                        format!("ERROR: Reading message: {}. Dropping connection", e),
+                    );
+                    reader_error = e;
+                    break;
+                }
+            };
+
+        match in_dispatcher.route(input_message) {
+            Ok(Some(m)) => {
+                process_message(&in_log_file, m, &in_dispatcher, &in_node, &in_limits).await
+            }
+            Ok(None) => {}
+            Err(e) => write_log(&in_log_file, format!("ERROR: {}", e)),
+        }
+    }
+
+    *in_end_flag.lock().unwrap() = true;
+    // The requests waiting for a response fail at once
+    in_dispatcher.fail_all(reader_error);
+}
+
+// Send our timed syncs until the connection is closed
+async fn send_timed_syncs(
+    in_log_file: Arc<Option<File>>,
+    in_dispatcher: Dispatcher,
+    in_node: Arc<Mutex<NodeState>>,
+    in_limits: DecodeLimits,
+    in_interval: Duration,
+) {
+    loop {
+        tokio::time::sleep(in_interval).await;
+
+        let request = match to_bytes(&in_node.lock().unwrap().timed_sync_request()) {
+            Ok(r) => r,
+            Err(e) => {
+                write_log(
+                    &in_log_file,
+                    format!("ERROR: Encoding Time sync request: {}", e),
+                );
+                return;
+            }
+        };
+
+        write_log(&in_log_file, "Sending Time sync request");
+        let result = match in_dispatcher.invoke(LevinCommand::TimedSync, request).await {
+            Ok(r) => in_node.lock().unwrap().process_timed_sync(&r, &in_limits),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => {
+                let node = in_node.lock().unwrap();
+                write_log(
+                    &in_log_file,
+                    format!(
+                        "Time sync response. Node height: {}. Known peers: {}",
+                        node.remote_payload_data()
+                            .map(|p| p.current_height)
+                            .unwrap_or_default(),
+                        node.peers().len()
+                    ),
+                );
+            }
+            Err(e) => {
+                write_log(&in_log_file, format!("ERROR: Time sync failed: {}", e));
+                return;
+            }
+        }
+    }
+}
+
+// Check that a node is reachable. Connect, send a ping without
+// handshake and print the peer id and the round trip time
+async fn run_ping(in_matches: &ArgMatches) -> Result<(), u32> {
+    let address = in_matches
+        .get_one::<SocketAddr>("address")
+        .expect("Please, enter a node address");
+
+    let start = Instant::now();
+    let node_stream = match timeout(PING_TIMEOUT, TcpStream::connect(address)).await {
+        Ok(Ok(n)) => n,
+        Ok(Err(e)) => {
+            println!("ERROR: Connecting to node: {}", e);
+            return Err(1);
+        }
+        Err(_) => {
+            println!(
+                "ERROR: Connecting to node: timeout after {:?}",
+                PING_TIMEOUT
             );
-            *out_end_flag.lock().unwrap() = true;
+            return Err(1);
+        }
+    };
+    let connect_time = start.elapsed();
+
+    let (read_half, write_half) = node_stream.into_split();
+    let mut node_reader = FramedRead::new(read_half, LevinCodec::default());
+    let mut node_writer = FramedWrite::new(write_half, LevinCodec::default());
+
+    let start = Instant::now();
+    let response = timeout(
+        PING_TIMEOUT,
+        ping(&mut node_reader, &mut node_writer, &DecodeLimits::default()),
+    )
+    .await;
+
+    match response {
+        Ok(Ok(r)) => {
+            println!(
+                "Ping {}: peer_id {:#018x}, round trip {:?} (connect {:?})",
+                address,
+                r.peer_id,
+                start.elapsed(),
+                connect_time
+            );
+            Ok(())
+        }
+        Ok(Err(e)) => {
+            println!("ERROR: Ping failed: {}", e);
+            Err(2)
+        }
+        Err(_) => {
+            println!("ERROR: Ping failed: timeout after {:?}", PING_TIMEOUT);
+            Err(2)
         }
     }
 }
 
+// Print a captured message as JSON
+fn run_decode(in_matches: &ArgMatches) -> Result<(), u32> {
+    let input = if let Some(h) = in_matches.get_one::<String>("hex") {
+        parse_hex(h)
+    } else if let Some(l) = in_matches.get_one::<String>("log") {
+        parse_log_line(l)
+    } else if let Some(f) = in_matches.get_one::<String>("file") {
+        std::fs::read(f).map_err(ConnectNodeError::from)
+    } else {
+        Err(ConnectNodeError::InvalidInput("No message"))
+    };
+
+    let bytes = match input {
+        Ok(b) => b,
+        Err(e) => {
+            println!("ERROR: Reading message: {}", e);
+            return Err(3);
+        }
+    };
+
+    if in_matches.get_flag("dump") {
+        return match dump_message(&bytes, &DecodeLimits::default()) {
+            Ok(d) => {
+                print!("{}", d);
+                Ok(())
+            }
+            Err(e) => {
+                println!("ERROR: Decoding message: {}", e);
+                Err(3)
+            }
+        };
+    }
+
+    match decode_message(
+        &bytes,
+        in_matches.get_flag("generic"),
+        &DecodeLimits::default(),
+    ) {
+        Ok(m) => {
+            println!("{}", serde_json::to_string_pretty(&m).unwrap_or_default());
+            Ok(())
+        }
+        Err(e) => {
+            println!("ERROR: Decoding message: {}", e);
+            Err(3)
+        }
+    }
+}
+
+// Encode a JSON file as a Levin message
+fn run_encode(in_matches: &ArgMatches) -> Result<(), u32> {
+    let json_file = in_matches
+        .get_one::<String>("json_file")
+        .expect("Please, enter a JSON file");
+    let command = LevinCommand::from(*in_matches.get_one::<u32>("command").unwrap_or(&1001));
+
+    let message = std::fs::read_to_string(json_file)
+        .map_err(ConnectNodeError::from)
+        .and_then(|j| encode_message(command, in_matches.get_flag("response"), &j));
+
+    let message = match message {
+        Ok(m) => m,
+        Err(e) => {
+            println!("ERROR: Encoding message: {}", e);
+            return Err(3);
+        }
+    };
+
+    match in_matches.get_one::<String>("output") {
+        Some(o) => {
+            if let Err(e) = std::fs::write(o, &message) {
+                println!("ERROR: Writing message: {}", e);
+                return Err(3);
+            }
+        }
+        None => println!("{}", hex::encode(&message)),
+    }
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), u32> {
     let matches = Command::new("Connect to Node")
         .version("1.0")
         .about("Connect to a Monero node in the specified network and perform the handshake")
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
         .arg(
             arg!(<ip_address> "Node IP Address")
                 .required(true)
@@ -307,9 +658,60 @@ async fn main() -> Result<(), u32> {
             )
             .required(false),
         )
-
+        .arg(arg!(-d --dump "Log an annotated hex dump of every payload"))
+        .arg(
+            arg!(
+                -t --"timed-sync" <seconds> "Send a timed sync to the node every <seconds>"
+            )
+            .value_parser(value_parser!(u64)),
+        )
+        .subcommand(
+            Command::new("decode")
+                .about("Decode a captured message and print it as JSON")
+                .arg(arg!(--hex <hex_string> "Message in hex"))
+                .arg(arg!(--file <raw_file> "File with the raw bytes of the message"))
+                .arg(arg!(
+                    --log <log_line> "Line of the log file with the bytes of the message"
+                ))
+                .group(
+                    ArgGroup::new("input")
+                        .args(["hex", "file", "log"])
+                        .required(true),
+                )
+                .arg(arg!(--generic "Decode the payload without the type of the command"))
+                .arg(arg!(--dump "Print an annotated hex dump of the payload instead of JSON")),
+        )
+        .subcommand(
+            Command::new("ping")
+                .about("Send a ping to a node, without handshake, and print its peer id")
+                .arg(
+                    arg!(<address> "Node address, i.e. 18.132.93.91:28080")
+                        .value_parser(value_parser!(SocketAddr)),
+                ),
+        )
+        .subcommand(
+            Command::new("encode")
+                .about("Encode a JSON payload, i.e. handshake.json, as a Levin message")
+                .arg(arg!(<json_file> "JSON file with the payload"))
+                .arg(
+                    arg!(--command <command> "Levin command")
+                        .value_parser(value_parser!(u32))
+                        .default_value("1001"),
+                )
+                .arg(arg!(--response "Encode the response to the command"))
+                .arg(arg!(
+                    -o --output <raw_file> "Write the raw bytes to a file instead of hex"
+                )),
+        )
         .get_matches();
 
+    match matches.subcommand() {
+        Some(("decode", m)) => return run_decode(m),
+        Some(("encode", m)) => return run_encode(m),
+        Some(("ping", m)) => return run_ping(m).await,
+        _ => {}
+    }
+
     let node_ip_address = matches
         .get_one::<Ipv4Addr>("ip_address")
         .expect("Please, enter a Node IP");
@@ -319,14 +721,16 @@ async fn main() -> Result<(), u32> {
         .expect("Please, enter a Node port");
     let node_port = tmp_node_port.parse::<u16>().unwrap_or(0);
 
-    let node_ip_address = matches
-        .get_one::<Ipv4Addr>("ip_address")
-        .expect("Please, enter a Node IP");
-
     let log_file_name = matches.get_one::<String>("output");
 
     let config_file_name = matches.get_one::<String>("config");
 
+    let dump_payloads = matches.get_flag("dump");
+
+    let timed_sync_interval = matches
+        .get_one::<u64>("timed-sync")
+        .map(|s| Duration::from_secs(*s));
+
     // println!("DEBUG: {} {}", node_ip_address, node_port);
     // if let Some(l) = log_file_name {
     //     println!("{}", l);
@@ -354,7 +758,7 @@ async fn main() -> Result<(), u32> {
     // Connect to the node
     let connection_string = format!("{}:{}", node_ip_address, node_port);
 
-    let mut node_stream = match TcpStream::connect(connection_string).await {
+    let node_stream = match TcpStream::connect(connection_string).await {
         Ok(n) => n,
         Err(e) => {
             write_log(&log_file, format!("ERROR: Connecting to node: {}", e));
@@ -364,48 +768,114 @@ async fn main() -> Result<(), u32> {
 
     write_log(&log_file, "Connected");
 
-    let tmp_config: Option<String> = match config_file_name {
-        Some(f) => Some(f.into()),
-        None => None,
+    let tmp_config: Option<String> = config_file_name.cloned();
+    let request = match build_request(tmp_config, &log_file) {
+        Ok(r) => r,
+        Err(_) => return Err(2),
     };
 
-    // Write data in the background
-    // Do Handshake
-    write_log(&log_file, "Performing handshake");
-    do_handshake(tmp_config, &log_file, &mut node_stream)
-        .await
-        .unwrap();
+    // Sizes accepted in the payloads of the node
+    let decode_limits = DecodeLimits::default();
+
+    // The handshake response of another network is rejected as soon
+    // as its network_id is received
+    let (read_half, write_half) = node_stream.into_split();
+    let node_reader = FramedRead::new(
+        read_half,
+        LevinCodec::default().with_payload_check(
+            NetworkIdCheck::new(request.node_data.network_id),
+            &decode_limits,
+        ),
+    );
+    // Longer messages are accepted once the handshake is completed
+    let handshake_completed = node_reader.decoder().handshake_flag();
+    let mut node_writer = FramedWrite::new(write_half, LevinCodec::default());
 
-    // Read message until Ctrl-C is pressed
-    // let mut end_flag = false;
+    let arc_log_file: Arc<Option<File>> = Arc::new(log_file);
     let arc_end_flag: Arc<Mutex<bool>> = Arc::new(Mutex::new(false));
+    let arc_node: Arc<Mutex<NodeState>> = Arc::new(Mutex::new(NodeState::new(
+        &request.node_data,
+        request.payload_data.clone(),
+    )));
 
-    let arc_log_file: Arc<Mutex<Option<File>>> = Arc::new(Mutex::new(log_file));
-
-    while !*arc_end_flag.lock().unwrap() {
-        let f = arc_log_file.lock().unwrap();
-        let input_message: P2PMessage = match read_message(&f, &mut node_stream).await {
-            Ok(m) => m,
-            Err(e) => {
-                write_log(&f, format!("ERROR: Reading message: {}", e));
-                sleep(Duration::from_millis(500));
-                continue;
+    // Write data in the background
+    let (outgoing_tx, mut outgoing_rx) = mpsc::channel::<P2PMessage>(32);
+    let writer_log_file = arc_log_file.clone();
+    let writer_task = tokio::spawn(async move {
+        while let Some(m) = outgoing_rx.recv().await {
+            if let Err(e) = node_writer.send(m).await {
+                write_log(&writer_log_file, format!("ERROR: Sending message: {}", e));
+                break;
             }
-        };
+        }
+        node_writer.into_inner()
+    });
+
+    let mut dispatcher = Dispatcher::new(outgoing_tx);
+    dispatcher.set_timeout(LevinCommand::Handshake, HANDSHAKE_TIMEOUT);
+
+    // Read message until the connection is closed
+    let reader_task = tokio::spawn(read_messages(
+        arc_log_file.clone(),
+        node_reader,
+        dispatcher.clone(),
+        arc_node.clone(),
+        decode_limits,
+        dump_payloads,
+        arc_end_flag.clone(),
+    ));
 
-        let tmp_log_file = arc_log_file.clone();
-        let tmp_end_flag = arc_end_flag.clone();
+    // Do Handshake
+    write_log(&arc_log_file, "Performing handshake");
+    let remote_flags = match do_handshake(
+        &request,
+        &arc_log_file,
+        &dispatcher,
+        &arc_node,
+        &decode_limits,
+        &handshake_completed,
+    )
+    .await
+    {
+        Ok(f) => f,
+        Err(e) => {
+            write_log(&arc_log_file, format!("ERROR: Handshake failed: {}", e));
+            return Err(2);
+        }
+    };
 
-        tokio::spawn(async {
-            process_message(tmp_log_file, input_message, tmp_end_flag).await;
-        })
-        .await
-        .unwrap();
-    }
+    negotiate_support_flags(
+        remote_flags,
+        &arc_log_file,
+        &dispatcher,
+        &arc_node,
+        &decode_limits,
+    )
+    .await;
+
+    // Our own timed syncs, if requested
+    let timed_sync_task = timed_sync_interval.map(|i| {
+        tokio::spawn(send_timed_syncs(
+            arc_log_file.clone(),
+            dispatcher.clone(),
+            arc_node.clone(),
+            decode_limits,
+            i,
+        ))
+    });
+
+    reader_task.await.unwrap();
 
     // Close connection
-    write_log(&arc_log_file.lock().unwrap(), "Closing connection");
-    node_stream.shutdown().await.unwrap();
+    write_log(&arc_log_file, "Closing connection");
+    if let Some(t) = timed_sync_task {
+        t.abort();
+        let _ = t.await;
+    }
+    drop(dispatcher);
+    if let Ok(mut w) = writer_task.await {
+        let _ = w.shutdown().await;
+    }
 
     Ok(())
 }
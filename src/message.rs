@@ -0,0 +1,233 @@
+// Message
+//
+// Typed payloads of the Levin messages. A message is decoded with the
+// command and the flags of its header, so the callers match on the
+// bodies instead of the commands. The commands that are not
+// implemented are kept as Unknown with their raw payload
+//
+
+use bytes::Bytes;
+use serde::Serialize;
+
+use crate::epee::{from_bytes, to_bytes, DecodeLimits};
+use crate::error::ConnectNodeError;
+use crate::protocol::{
+    Command, HandshakeRequest, HandshakeResponse, Header, LevinFlags, NewTransactions, P2PMessage,
+    PingRequest, PingResponse, ReturnCode, SupportFlagsRequest, SupportFlagsResponse,
+    TimedSyncRequest, TimedSyncResponse,
+};
+
+// In JSON, only the body is written
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Message {
+    HandshakeRequest(HandshakeRequest),
+    HandshakeResponse(HandshakeResponse),
+    TimedSyncRequest(TimedSyncRequest),
+    TimedSyncResponse(TimedSyncResponse),
+    PingRequest(PingRequest),
+    PingResponse(PingResponse),
+    SupportFlagsRequest(SupportFlagsRequest),
+    SupportFlagsResponse(SupportFlagsResponse),
+    // Notifications
+    NewTransactions(NewTransactions),
+    // Commands that are not implemented, fragments and noise. The flags
+    // and the return code are kept, so it is encoded with the same header
+    Unknown {
+        command: Command,
+        flags: LevinFlags,
+        return_code: ReturnCode,
+        raw: Bytes,
+    },
+}
+
+impl Message {
+    // The payload is decoded with the type of the command. An error
+    // is only returned if the payload of a known command is not valid.
+    // The unknown payloads are not copied, they share in_body
+    pub fn decode(
+        in_header: &Header,
+        in_body: &Bytes,
+        in_limits: &DecodeLimits,
+    ) -> Result<Self, ConnectNodeError> {
+        let is_response = in_header.flags.contains(LevinFlags::RESPONSE);
+
+        let output = match (in_header.command, is_response) {
+            (Command::Handshake, false) => {
+                Message::HandshakeRequest(from_bytes(in_body, in_limits)?)
+            }
+            (Command::Handshake, true) => {
+                Message::HandshakeResponse(from_bytes(in_body, in_limits)?)
+            }
+            (Command::TimedSync, false) => {
+                Message::TimedSyncRequest(from_bytes(in_body, in_limits)?)
+            }
+            (Command::TimedSync, true) => {
+                Message::TimedSyncResponse(from_bytes(in_body, in_limits)?)
+            }
+            (Command::Ping, false) => Message::PingRequest(from_bytes(in_body, in_limits)?),
+            (Command::Ping, true) => Message::PingResponse(from_bytes(in_body, in_limits)?),
+            (Command::SupportFlags, false) => {
+                Message::SupportFlagsRequest(from_bytes(in_body, in_limits)?)
+            }
+            (Command::SupportFlags, true) => {
+                Message::SupportFlagsResponse(from_bytes(in_body, in_limits)?)
+            }
+            (Command::NewTransactions, false) => {
+                Message::NewTransactions(from_bytes(in_body, in_limits)?)
+            }
+            (command, _) => Message::Unknown {
+                command,
+                flags: in_header.flags,
+                return_code: in_header.return_code,
+                raw: in_body.clone(),
+            },
+        };
+
+        Ok(output)
+    }
+
+    // Message received from the node
+    pub fn from_p2p(
+        in_message: &P2PMessage,
+        in_limits: &DecodeLimits,
+    ) -> Result<Self, ConnectNodeError> {
+        Message::decode(&in_message.header, &in_message.buffer, in_limits)
+    }
+
+    pub fn command(&self) -> Command {
+        match self {
+            Message::HandshakeRequest(_) | Message::HandshakeResponse(_) => Command::Handshake,
+            Message::TimedSyncRequest(_) | Message::TimedSyncResponse(_) => Command::TimedSync,
+            Message::PingRequest(_) | Message::PingResponse(_) => Command::Ping,
+            Message::SupportFlagsRequest(_) | Message::SupportFlagsResponse(_) => {
+                Command::SupportFlags
+            }
+            Message::NewTransactions(_) => Command::NewTransactions,
+            Message::Unknown { command, .. } => *command,
+        }
+    }
+
+    pub fn is_response(&self) -> bool {
+        matches!(
+            self,
+            Message::HandshakeResponse(_)
+                | Message::TimedSyncResponse(_)
+                | Message::PingResponse(_)
+                | Message::SupportFlagsResponse(_)
+        )
+    }
+
+    // Levin message with the header of the body. The unknown messages
+    // are written with their original flags and return code
+    pub fn encode(&self) -> Result<P2PMessage, ConnectNodeError> {
+        let body: Bytes = match self {
+            Message::HandshakeRequest(b) => to_bytes(b)?.into(),
+            Message::HandshakeResponse(b) => to_bytes(b)?.into(),
+            Message::TimedSyncRequest(b) => to_bytes(b)?.into(),
+            Message::TimedSyncResponse(b) => to_bytes(b)?.into(),
+            Message::PingRequest(b) => to_bytes(b)?.into(),
+            Message::PingResponse(b) => to_bytes(b)?.into(),
+            Message::SupportFlagsRequest(b) => to_bytes(b)?.into(),
+            Message::SupportFlagsResponse(b) => to_bytes(b)?.into(),
+            Message::NewTransactions(b) => to_bytes(b)?.into(),
+            Message::Unknown {
+                command,
+                flags,
+                return_code,
+                raw,
+            } => {
+                let mut header = Header::new();
+                header.command = *command;
+                header.flags = *flags;
+                header.return_code = *return_code;
+                // Only the requests of the admin commands wait for a response
+                header.expected_response =
+                    u8::from(flags.contains(LevinFlags::REQUEST) && command.is_admin());
+                header.msg_length = raw.len() as u64;
+
+                return Ok(P2PMessage {
+                    header,
+                    buffer: raw.clone(),
+                });
+            }
+        };
+
+        let command = self.command();
+        if self.is_response() {
+            P2PMessage::response(command, body)
+        } else if command.is_notification() {
+            P2PMessage::notify(command, body)
+        } else {
+            P2PMessage::request(command, body)
+        }
+    }
+}
+
+#[test]
+fn message_decode_encode() {
+    use crate::protocol::{PayloadType, ReturnCode, PING_OK_RESPONSE_STATUS_TEXT};
+
+    let limits = DecodeLimits::default();
+
+    let request = Message::TimedSyncRequest(TimedSyncRequest {
+        payload_data: PayloadType {
+            current_height: 100,
+            ..Default::default()
+        },
+    });
+    let encoded = request.encode().unwrap();
+    assert_eq!(Command::TimedSync, encoded.header.command);
+    assert_eq!(1, encoded.header.expected_response);
+    assert_eq!(encoded.buffer.len() as u64, encoded.header.msg_length);
+    assert_eq!(request, Message::from_p2p(&encoded, &limits).unwrap());
+
+    let response = Message::PingResponse(PingResponse {
+        peer_id: 0x1234,
+        status: PING_OK_RESPONSE_STATUS_TEXT.to_string(),
+    });
+    let encoded = response.encode().unwrap();
+    assert!(encoded.header.flags.contains(LevinFlags::RESPONSE));
+    assert_eq!(ReturnCode::Success, encoded.header.return_code);
+    assert_eq!(response, Message::from_p2p(&encoded, &limits).unwrap());
+    assert_eq!(
+        r#"{"peer_id":4660,"status":"OK"}"#,
+        serde_json::to_string(&response).unwrap()
+    );
+
+    // Unknown commands are kept, with their payload
+    let notify = P2PMessage::notify(Command::from(2099), vec![1, 2, 3]).unwrap();
+    let unknown = Message::from_p2p(&notify, &limits).unwrap();
+    assert_eq!(
+        Message::Unknown {
+            command: Command::Unknown(2099),
+            flags: LevinFlags::REQUEST,
+            return_code: ReturnCode::Ok,
+            raw: Bytes::from_static(&[1, 2, 3]),
+        },
+        unknown
+    );
+    assert_eq!(notify.to_bytes(), unknown.encode().unwrap().to_bytes());
+    if let Message::Unknown { raw, .. } = &unknown {
+        assert_eq!(notify.buffer.as_ptr(), raw.as_ptr());
+    }
+
+    // A response of an unknown command keeps its header
+    let mut response = P2PMessage::response(Command::from(1005), vec![4]).unwrap();
+    response.header.return_code = ReturnCode::ErrorFormat;
+    let unknown = Message::from_p2p(&response, &limits).unwrap();
+    assert_eq!(response.to_bytes(), unknown.encode().unwrap().to_bytes());
+
+    let notification = Message::NewTransactions(NewTransactions {
+        txs: vec![vec![1, 2]],
+        ..Default::default()
+    });
+    let encoded = notification.encode().unwrap();
+    assert_eq!(0, encoded.header.expected_response);
+    assert_eq!(notification, Message::from_p2p(&encoded, &limits).unwrap());
+
+    // The payload of a known command must be valid
+    let mut invalid = P2PMessage::new_command(Command::Ping).unwrap();
+    invalid.buffer = Bytes::from_static(&[1, 2, 3]);
+    assert!(Message::from_p2p(&invalid, &limits).is_err());
+}
@@ -1,16 +1,18 @@
 use std::{
-    fmt::Debug,
-    net::{self, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs},
+    fmt::{self, Debug},
+    net::{self, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    str::FromStr,
 };
 
-use epee_encoding::{
-    io::{Read, Write},
-    read_epee_value, write_field, EpeeObject, EpeeObjectBuilder,
-};
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ConnectNodeError;
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Default, Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 pub enum NetworkAddressTypeEnum {
+    #[default]
     INVALID = 0,
     IPV4 = 1,
     IPV6 = 2,
@@ -18,12 +20,6 @@ pub enum NetworkAddressTypeEnum {
     TOR = 4,
 }
 
-impl Default for NetworkAddressTypeEnum {
-    fn default() -> Self {
-        Self::INVALID
-    }
-}
-
 impl NetworkAddressTypeEnum {
     pub fn from_u8(in_value: u8) -> NetworkAddressTypeEnum {
         match in_value {
@@ -36,7 +32,7 @@ impl NetworkAddressTypeEnum {
         }
     }
 
-    pub fn to_u8(&self) -> u8 {
+    pub fn to_u8(self) -> u8 {
         match self {
             NetworkAddressTypeEnum::INVALID => 0,
             NetworkAddressTypeEnum::IPV4 => 1,
@@ -54,379 +50,512 @@ pub enum NetZone {
     I2p,
 }
 
-#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
-pub struct NetworkAddress {
-    pub addr_type: NetworkAddressTypeEnum,
-    pub addr: [u8; 16],
-    pub port: u16,
+// Kind of an address. The IPv4-mapped IPv6 addresses have the kind
+// of the IPv4 address
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AddressClass {
+    Unspecified,
+    Loopback,
+    // RFC 1918 and unique local (fc00::/7)
+    Private,
+    LinkLocal,
+    Multicast,
+    // 192.0.2.0/24, 198.51.100.0/24, 203.0.113.0/24 and 2001:db8::/32
+    Documentation,
+    // The rest of the ranges that are not used in Internet: 0.0.0.0/8,
+    // shared (100.64.0.0/10), benchmarking (198.18.0.0/15), 240.0.0.0/4
+    // and broadcast
+    Reserved,
+    Public,
+    // Tor and I2P
+    Overlay,
 }
 
-#[derive(Default, Debug)]
-pub struct __NetworkAddress {
-    pub addr_type: NetworkAddressTypeEnum,
-    pub addr: Option<[u8; 16]>,
-    pub port: Option<u16>,
+// Suffixes and maximum length of the hosts of the anonymity networks,
+// as monerod
+pub const TOR_HOST_SUFFIX: &str = ".onion";
+pub const I2P_HOST_SUFFIX: &str = ".b32.i2p";
+pub const MAX_TOR_HOST_LENGTH: usize = 56 + TOR_HOST_SUFFIX.len();
+pub const MAX_I2P_HOST_LENGTH: usize = 52 + I2P_HOST_SUFFIX.len();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkAddress {
+    Ipv4(SocketAddrV4),
+    Ipv6(SocketAddrV6),
+    Tor { host: String, port: u16 },
+    I2p { host: String, port: u16 },
 }
 
-impl EpeeObjectBuilder<NetworkAddress> for __NetworkAddress {
-    fn add_field<R: Read>(&mut self, name: &str, r: &mut R) -> epee_encoding::error::Result<bool> {
-        match name {
-            "addr" => {
-                self.addr = Some(read_epee_value(r)?);
-            }
-            _ => return Ok(false),
+// Epee layout of monerod:
+//   { addr: { m_ip, m_port }, type: 1 } for IPv4
+//   { addr: { addr, m_port }, type: 2 } for IPv6
+//   { addr: { host, port }, type: 3 } for I2P
+//   { addr: { host, port }, type: 4 } for Tor
+// m_ip is in network byte order, so its little endian bytes are
+// the octets of the address. In JSON it is "host:port"
+impl Serialize for NetworkAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
         }
-        println!("DEBUG: Add NetAddr. addr = {:x?}", self.addr);
-        Ok(true)
+
+        TaggedNetworkAddress::from(self).serialize(serializer)
     }
+}
 
-    fn finish(self) -> epee_encoding::error::Result<NetworkAddress> {
-        println!("DEBUG: Finishing NetAdd. addr = {:x?}", self.addr);
+impl<'de> Deserialize<'de> for NetworkAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let address = String::deserialize(deserializer)?;
+            return address.parse().map_err(D::Error::custom);
+        }
 
-        Ok(NetworkAddress {
-            addr_type: self.addr_type,
-            addr: self.addr.ok_or_else(|| {
-                println!("DEBUG: Finishing NetAdd. addr = {:x?}", self.addr);
-                epee_encoding::error::Error::Format("Required field was not found!")
-            })?,
-            port: self.port.unwrap().clone(),
-        })
+        TaggedNetworkAddress::deserialize(deserializer)?
+            .try_into()
+            .map_err(D::Error::custom)
     }
 }
 
-impl EpeeObject for NetworkAddress {
-    type Builder = TaggedNetworkAddress;
+impl NetworkAddress {
+    // Host of an anonymity network. It must have the suffix of the network
+    pub fn tor(in_host: impl Into<String>, in_port: u16) -> Result<Self, ConnectNodeError> {
+        let host = in_host.into();
+        check_host(&host, TOR_HOST_SUFFIX, MAX_TOR_HOST_LENGTH)?;
+
+        Ok(NetworkAddress::Tor {
+            host,
+            port: in_port,
+        })
+    }
+
+    pub fn i2p(in_host: impl Into<String>, in_port: u16) -> Result<Self, ConnectNodeError> {
+        let host = in_host.into();
+        check_host(&host, I2P_HOST_SUFFIX, MAX_I2P_HOST_LENGTH)?;
 
-    fn number_of_fields(&self) -> u64 {
-        2
+        Ok(NetworkAddress::I2p {
+            host,
+            port: in_port,
+        })
     }
 
-    fn write_fields<W: Write>(&self, w: &mut W) -> epee_encoding::error::Result<()> {
-        // write the fields
-        println!("DEBUG: TaggedNetworkAddress::from(self).write_fields(w) ");
-        TaggedNetworkAddress::from(self).write_fields(w)
+    pub fn addr_type(&self) -> NetworkAddressTypeEnum {
+        match self {
+            NetworkAddress::Ipv4(_) => NetworkAddressTypeEnum::IPV4,
+            NetworkAddress::Ipv6(_) => NetworkAddressTypeEnum::IPV6,
+            NetworkAddress::Tor { .. } => NetworkAddressTypeEnum::TOR,
+            NetworkAddress::I2p { .. } => NetworkAddressTypeEnum::I2P,
+        }
     }
-}
 
-impl NetworkAddress {
     pub fn get_zone(&self) -> NetZone {
-        NetZone::Public
+        match self {
+            NetworkAddress::Ipv4(_) | NetworkAddress::Ipv6(_) => NetZone::Public,
+            NetworkAddress::Tor { .. } => NetZone::Tor,
+            NetworkAddress::I2p { .. } => NetZone::I2p,
+        }
+    }
+
+    pub fn classify(&self) -> AddressClass {
+        match self {
+            NetworkAddress::Ipv4(a) => classify_ipv4(a.ip()),
+            NetworkAddress::Ipv6(a) => match a.ip().to_ipv4_mapped() {
+                Some(ip) => classify_ipv4(&ip),
+                None => classify_ipv6(a.ip()),
+            },
+            NetworkAddress::Tor { .. } | NetworkAddress::I2p { .. } => AddressClass::Overlay,
+        }
     }
 
     pub fn is_loopback(&self) -> bool {
-        // TODO
-        false
+        self.classify() == AddressClass::Loopback
     }
 
+    // Address of the local network, private or link-local
     pub fn is_local(&self) -> bool {
-        // TODO
-        false
+        matches!(
+            self.classify(),
+            AddressClass::Private | AddressClass::LinkLocal
+        )
     }
 
-    pub fn port(&self) -> u16 {
-        self.port()
+    // IP address that can be dialed through Internet. The Tor and I2P
+    // addresses are only reachable through their own networks
+    pub fn is_publicly_routable(&self) -> bool {
+        self.classify() == AddressClass::Public && self.port() != 0
     }
-}
 
-impl From<net::SocketAddrV4> for NetworkAddress {
-    fn from(value: net::SocketAddrV4) -> Self {
-        let mut tmp_buffer: [u8; 16] = [0; 16];
-        for i in 0..4 {
-            tmp_buffer[i] = value.ip().octets()[i];
+    pub fn port(&self) -> u16 {
+        match self {
+            NetworkAddress::Ipv4(a) => a.port(),
+            NetworkAddress::Ipv6(a) => a.port(),
+            NetworkAddress::Tor { port, .. } | NetworkAddress::I2p { port, .. } => *port,
         }
+    }
 
-        println!("DEBUG: IPv4 value: {:?} ", value);
-
-        NetworkAddress {
-            addr_type: NetworkAddressTypeEnum::IPV4,
-            addr: tmp_buffer,
-            port: value.port(),
+    // None for the addresses that are not IP
+    pub fn to_socket_addr(&self) -> Option<SocketAddr> {
+        match self {
+            NetworkAddress::Ipv4(a) => Some(SocketAddr::V4(*a)),
+            NetworkAddress::Ipv6(a) => Some(SocketAddr::V6(*a)),
+            NetworkAddress::Tor { .. } | NetworkAddress::I2p { .. } => None,
         }
     }
 }
 
-impl From<net::SocketAddrV6> for NetworkAddress {
-    fn from(value: net::SocketAddrV6) -> Self {
-        let mut tmp_buffer: [u8; 16] = [0; 16];
-        for i in 0..16 {
-            tmp_buffer[i] = value.ip().octets()[i];
-        }
-
-        println!("DEBUG: IPv6 value: {:?} ", value);
-
-        NetworkAddress {
-            addr_type: NetworkAddressTypeEnum::IPV6,
-            addr: tmp_buffer,
-            port: value.port(),
-        }
+fn classify_ipv4(in_ip: &Ipv4Addr) -> AddressClass {
+    let [a, b, ..] = in_ip.octets();
+
+    if in_ip.is_unspecified() {
+        AddressClass::Unspecified
+    } else if in_ip.is_loopback() {
+        AddressClass::Loopback
+    } else if in_ip.is_private() {
+        AddressClass::Private
+    } else if in_ip.is_link_local() {
+        AddressClass::LinkLocal
+    } else if in_ip.is_multicast() {
+        AddressClass::Multicast
+    } else if in_ip.is_documentation() {
+        AddressClass::Documentation
+    } else if a == 0 || (a == 100 && b & 0xc0 == 64) || (a == 198 && b & 0xfe == 18) || a >= 240 {
+        AddressClass::Reserved
+    } else {
+        AddressClass::Public
     }
 }
 
-impl From<SocketAddr> for NetworkAddress {
-    fn from(value: SocketAddr) -> Self {
-        match value {
-            SocketAddr::V4(v4) => v4.into(),
-            SocketAddr::V6(v6) => v6.into(),
-        }
+fn classify_ipv6(in_ip: &Ipv6Addr) -> AddressClass {
+    if in_ip.is_unspecified() {
+        AddressClass::Unspecified
+    } else if in_ip.is_loopback() {
+        AddressClass::Loopback
+    } else if in_ip.is_unique_local() {
+        AddressClass::Private
+    } else if in_ip.is_unicast_link_local() {
+        AddressClass::LinkLocal
+    } else if in_ip.is_multicast() {
+        AddressClass::Multicast
+    } else if in_ip.segments()[..2] == [0x2001, 0xdb8] {
+        AddressClass::Documentation
+    } else {
+        AddressClass::Public
     }
 }
 
-#[derive(Default, Debug)]
-pub struct TaggedNetworkAddress {
-    pub ty: Option<u8>,
-    pub addr: Option<AllFieldsNetworkAddress>,
-}
+fn check_host(
+    in_host: &str,
+    in_suffix: &str,
+    in_max_length: usize,
+) -> Result<(), ConnectNodeError> {
+    if in_host.len() <= in_suffix.len() || !in_host.ends_with(in_suffix) {
+        return Err(ConnectNodeError::InvalidAddress(
+            "Host of the wrong network",
+        ));
+    }
+    if in_host.len() > in_max_length {
+        return Err(ConnectNodeError::InvalidAddress("Host too long"));
+    }
 
-#[derive(Default, Debug)]
-pub struct __TaggedNetworkAddress {
-    pub ty: Option<u8>,
-    pub addr: Option<AllFieldsNetworkAddress>,
+    Ok(())
 }
 
-impl EpeeObjectBuilder<NetworkAddress> for TaggedNetworkAddress {
-    fn add_field<B: epee_encoding::io::Read>(
-        // + epee_encoding::io::Read>(
-        &mut self,
-        name: &str,
-        b: &mut B,
-    ) -> Result<bool, epee_encoding::Error> {
-        println!(
-            "DEBUG: EpeeObjectBuilder<NetworkAddress> : name {:?} ",
-            name
-        );
-
-        match name {
-            "type" => {
-                if std::mem::replace(&mut self.ty, Some(epee_encoding::read_epee_value(b)?))
-                    .is_some()
-                {
-                    return Err(epee_encoding::Error::Format(
-                        "Duplicate field type in data.",
-                    ));
-                }
-                Ok(true)
-            }
-            "addr" => {
-                if std::mem::replace(&mut self.addr, epee_encoding::read_epee_value(b)?).is_some() {
-                    return Err(epee_encoding::Error::Format(
-                        "Duplicate field addr in data.",
-                    ));
-                }
-                Ok(true)
+impl fmt::Display for NetworkAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NetworkAddress::Ipv4(a) => write!(f, "{}", a),
+            NetworkAddress::Ipv6(a) => write!(f, "{}", a),
+            NetworkAddress::Tor { host, port } | NetworkAddress::I2p { host, port } => {
+                write!(f, "{}:{}", host, port)
             }
-            _ => Ok(false),
         }
     }
-
-    fn finish(self) -> Result<NetworkAddress, epee_encoding::Error> {
-        println!("DEBUG: EpeeObjectBuilder<NetworkAddress> : finish");
-        self.try_into()
-            .map_err(|_| epee_encoding::Error::Value("Invalid network address"))
-    }
 }
 
-impl EpeeObjectBuilder<TaggedNetworkAddress> for __TaggedNetworkAddress {
-    fn add_field<Buf: epee_encoding::io::Read>(
-        &mut self,
-        name: &str,
-        b: &mut Buf,
-    ) -> Result<bool, epee_encoding::Error> {
-        println!(
-            "DEBUG: EpeeObjectBuilder<TaggedNetworkAddress> : name {:?} ",
-            name
-        );
-
-        match name {
-            "type" => {
-                if std::mem::replace(&mut self.ty, Some(epee_encoding::read_epee_value(b)?))
-                    .is_some()
-                {
-                    return Err(epee_encoding::Error::Format("Duplicate field in data."));
-                }
-                Ok(true)
-            }
-            "addr" => {
-                if std::mem::replace(&mut self.addr, epee_encoding::read_epee_value(b)?).is_some() {
-                    return Err(epee_encoding::Error::Format("Duplicate field in data."));
-                }
-                Ok(true)
-            }
-            _ => Ok(false),
-        }
-    }
+// "ip:port", "[ipv6]:port", "host.onion:port" or "host.b32.i2p:port"
+impl FromStr for NetworkAddress {
+    type Err = ConnectNodeError;
 
-    fn finish(self) -> Result<TaggedNetworkAddress, epee_encoding::Error> {
-        println!("DEBUG: EpeeObjectBuilder<TaggedNetworkAddress> finish ");
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(a) = s.parse::<SocketAddr>() {
+            return Ok(a.into());
+        }
 
-        self.try_into()
-            .map_err(|_| epee_encoding::Error::Value("Invalid network address"))
+        let (host, port) = s
+            .rsplit_once(':')
+            .ok_or(ConnectNodeError::InvalidAddress("Missing port"))?;
+        let port = port
+            .parse()
+            .map_err(|_| ConnectNodeError::InvalidAddress("Invalid port"))?;
+
+        if host.ends_with(TOR_HOST_SUFFIX) {
+            NetworkAddress::tor(host, port)
+        } else if host.ends_with(I2P_HOST_SUFFIX) {
+            NetworkAddress::i2p(host, port)
+        } else {
+            Err(ConnectNodeError::InvalidAddress("Unknown host"))
+        }
     }
 }
 
-impl EpeeObject for TaggedNetworkAddress {
-    type Builder = __TaggedNetworkAddress;
-
-    fn number_of_fields(&self) -> u64 {
-        2
+impl From<net::SocketAddrV4> for NetworkAddress {
+    fn from(value: net::SocketAddrV4) -> Self {
+        NetworkAddress::Ipv4(value)
     }
+}
 
-    fn write_fields<W: Write>(&self, w: &mut W) -> epee_encoding::error::Result<()> {
-        // write the fields
-        println!("DEBUG: EpeeObject for TaggedNetworkAddress  write_fields ");
-
-        write_field(&self.ty, "type", w)?;
-        write_field(&self.addr, "addr", w)
+impl From<net::SocketAddrV6> for NetworkAddress {
+    fn from(value: net::SocketAddrV6) -> Self {
+        NetworkAddress::Ipv6(value)
     }
 }
 
-impl TryFrom<__TaggedNetworkAddress> for TaggedNetworkAddress {
-    fn try_from(value: __TaggedNetworkAddress) -> Result<Self, Self::Error> {
-        println!(
-            "DEBUG: TryFrom<__TaggedNetworkAddress>  value: {:?} ",
-            value
-        );
-
-        Ok(TaggedNetworkAddress {
-            ty: value.ty.clone(),
-            addr: value.addr,
-        })
+impl From<SocketAddr> for NetworkAddress {
+    fn from(value: SocketAddr) -> Self {
+        match value {
+            SocketAddr::V4(v4) => v4.into(),
+            SocketAddr::V6(v6) => v6.into(),
+        }
     }
+}
 
-    type Error = epee_encoding::Error;
+// Fields of the adr section
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaggedNetworkAddress {
+    #[serde(default)]
+    pub addr: Option<AllFieldsNetworkAddress>,
+    #[serde(default, rename = "type")]
+    pub ty: Option<u8>,
 }
+
 impl TryFrom<TaggedNetworkAddress> for NetworkAddress {
-    type Error = epee_encoding::Error;
+    type Error = ConnectNodeError;
 
     fn try_from(value: TaggedNetworkAddress) -> Result<Self, Self::Error> {
-        println!(
-            "DEBUG: TryFrom<TaggedNetworkAddress>   try_from. value: {:?} ",
-            value
-        );
-
         value
             .addr
-            .ok_or(epee_encoding::Error::Value("Invalid Network Address"))?
+            .ok_or(ConnectNodeError::InvalidAddress("Missing field addr"))?
             .try_into_network_address(
                 value
                     .ty
-                    .ok_or(epee_encoding::Error::Value("Invalid Network Address"))?,
+                    .ok_or(ConnectNodeError::InvalidAddress("Missing field type"))?,
             )
-            .ok_or(epee_encoding::Error::Value("Invalid Network Address"))
     }
 }
 
-impl TryFrom<__TaggedNetworkAddress> for NetworkAddress {
-    type Error = epee_encoding::Error;
-
-    fn try_from(value: __TaggedNetworkAddress) -> Result<Self, Self::Error> {
-        println!(
-            "DEBUG: TryFrom<__TaggedNetworkAddress>  try_from. value: {:?} ",
-            value
-        );
-
-        value
-            .addr
-            .ok_or(epee_encoding::Error::Value("Invalid Network Address"))?
-            .try_into_network_address(
-                value
-                    .ty
-                    .ok_or(epee_encoding::Error::Value("Invalid Network Address"))?,
-            )
-            .ok_or(epee_encoding::Error::Value("Invalid Network Address"))
-    }
-}
 impl From<&NetworkAddress> for TaggedNetworkAddress {
     fn from(value: &NetworkAddress) -> Self {
-        println!("DEBUG: From<&NetworkAddress> from. value: {:?} ", value);
-
-        let mut tmp_ipv4: [u8; 4] = [0; 4];
-        for i in 0..4 {
-            tmp_ipv4[i] = value.addr[i];
-        }
-        match value.addr_type {
-            // NetworkAddress { addr) => match addr {
-            NetworkAddressTypeEnum::IPV4 => TaggedNetworkAddress {
-                ty: Some(1),
-                addr: Some(AllFieldsNetworkAddress {
-                    m_ip: Some(u32::from_be_bytes(tmp_ipv4)),
-                    m_port: Some(value.port()),
-                    addr: None,
-                }),
+        let addr = match value {
+            NetworkAddress::Ipv4(a) => AllFieldsNetworkAddress {
+                m_ip: Some(u32::from_le_bytes(a.ip().octets())),
+                m_port: Some(a.port()),
+                ..Default::default()
             },
-            NetworkAddressTypeEnum::IPV6 => TaggedNetworkAddress {
-                ty: Some(2),
-                addr: Some(AllFieldsNetworkAddress {
-                    addr: Some(value.addr),
-                    m_port: Some(value.port()),
-                    m_ip: None,
-                }),
+            NetworkAddress::Ipv6(a) => AllFieldsNetworkAddress {
+                addr: Some(a.ip().octets()),
+                m_port: Some(a.port()),
+                ..Default::default()
             },
-            NetworkAddressTypeEnum::INVALID => todo!(),
-            NetworkAddressTypeEnum::I2P => todo!(),
-            NetworkAddressTypeEnum::TOR => todo!(),
-            // },
+            NetworkAddress::Tor { host, port } | NetworkAddress::I2p { host, port } => {
+                AllFieldsNetworkAddress {
+                    host: Some(host.clone()),
+                    port: Some(*port),
+                    ..Default::default()
+                }
+            }
+        };
+
+        TaggedNetworkAddress {
+            ty: Some(value.addr_type().to_u8()),
+            addr: Some(addr),
         }
     }
 }
 
-impl From<NetworkAddress> for __TaggedNetworkAddress {
-    fn from(value: NetworkAddress) -> Self {
-        println!("DEBUG: From<NetworkAddress> from. value: {:?} ", value);
+// Fields of the addr section of all the types. Only the fields of
+// the type are written
+#[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AllFieldsNetworkAddress {
+    #[serde(default, with = "crate::epee::hash::option")]
+    pub addr: Option<[u8; 16]>,
+    #[serde(default)]
+    pub host: Option<String>,
+    #[serde(default)]
+    pub m_ip: Option<u32>,
+    #[serde(default)]
+    pub m_port: Option<u16>,
+    #[serde(default)]
+    pub port: Option<u16>,
+}
 
-        let mut tmp_ipv4: [u8; 4] = [0; 4];
-        for i in 0..4 {
-            tmp_ipv4[i] = value.addr[i];
+impl AllFieldsNetworkAddress {
+    fn try_into_network_address(self, ty: u8) -> Result<NetworkAddress, ConnectNodeError> {
+        let ty = NetworkAddressTypeEnum::from_u8(ty);
+
+        // The anonymity networks have a host and a port
+        if matches!(
+            ty,
+            NetworkAddressTypeEnum::TOR | NetworkAddressTypeEnum::I2P
+        ) {
+            let host = self
+                .host
+                .ok_or(ConnectNodeError::InvalidAddress("Missing field host"))?;
+            let port = self
+                .port
+                .ok_or(ConnectNodeError::InvalidAddress("Missing field port"))?;
+
+            return if ty == NetworkAddressTypeEnum::TOR {
+                NetworkAddress::tor(host, port)
+            } else {
+                NetworkAddress::i2p(host, port)
+            };
         }
 
-        match value.addr_type {
-            // NetworkAddress { addr) => match addr {
-            NetworkAddressTypeEnum::IPV4 => __TaggedNetworkAddress {
-                ty: Some(1),
-                addr: Some(AllFieldsNetworkAddress {
-                    m_ip: Some(u32::from_be_bytes(tmp_ipv4)),
-                    m_port: Some(value.port()),
-                    addr: None,
-                }),
-            },
-            NetworkAddressTypeEnum::IPV6 => __TaggedNetworkAddress {
-                ty: Some(2),
-                addr: Some(AllFieldsNetworkAddress {
-                    addr: Some(value.addr),
-                    m_port: Some(value.port()),
-                    m_ip: None,
-                }),
-            },
-            NetworkAddressTypeEnum::INVALID => todo!(),
-            NetworkAddressTypeEnum::I2P => todo!(),
-            NetworkAddressTypeEnum::TOR => todo!(),
-            // },
+        let port = self
+            .m_port
+            .ok_or(ConnectNodeError::InvalidAddress("Missing field m_port"))?;
+
+        match ty {
+            NetworkAddressTypeEnum::IPV4 => {
+                let ip = self
+                    .m_ip
+                    .ok_or(ConnectNodeError::InvalidAddress("Missing field m_ip"))?;
+                Ok(NetworkAddress::from(SocketAddrV4::new(
+                    Ipv4Addr::from(ip.to_le_bytes()),
+                    port,
+                )))
+            }
+            NetworkAddressTypeEnum::IPV6 => {
+                let ip = self
+                    .addr
+                    .ok_or(ConnectNodeError::InvalidAddress("Missing field addr"))?;
+                Ok(NetworkAddress::from(SocketAddrV6::new(
+                    Ipv6Addr::from(ip),
+                    port,
+                    0,
+                    0,
+                )))
+            }
+            other => Err(ConnectNodeError::UnsupportedAddressType(other)),
         }
     }
 }
 
-#[derive(Default, Debug, EpeeObject)]
-pub struct AllFieldsNetworkAddress {
-    pub m_ip: Option<u32>,
-    pub m_port: Option<u16>,
-    pub addr: Option<[u8; 16]>,
+#[test]
+fn network_address_epee() {
+    let ipv4 = NetworkAddress::from(SocketAddr::from(([185, 240, 242, 36], 18080)));
+    let bytes = crate::epee::to_bytes(&ipv4).unwrap();
+    // addr: { m_ip: 185.240.242.36, m_port: 18080 }, type: 1
+    assert_eq!(
+        [
+            8, 4, b'a', b'd', b'd', b'r', 12, 8, 4, b'm', b'_', b'i', b'p', 6, 185, 240, 242, 36,
+            6, b'm', b'_', b'p', b'o', b'r', b't', 7, 160, 70, 4, b't', b'y', b'p', b'e', 8, 1,
+        ],
+        bytes[9..]
+    );
+    assert_eq!(
+        ipv4,
+        crate::epee::from_bytes(&bytes, &Default::default()).unwrap()
+    );
+
+    // Same derives in JSON
+    let json = serde_json::to_string(&ipv4).unwrap();
+    assert_eq!(r#""185.240.242.36:18080""#, json);
+    assert_eq!(ipv4, serde_json::from_str(&json).unwrap());
+
+    let ipv6 = NetworkAddress::from(SocketAddr::from((
+        Ipv6Addr::from([0, 0, 0, 0, 0, 0xffff, 0x4190, 0x877d]),
+        18080,
+    )));
+    let bytes = crate::epee::to_bytes(&ipv6).unwrap();
+    assert_eq!(
+        ipv6,
+        crate::epee::from_bytes(&bytes, &Default::default()).unwrap()
+    );
+
+    // Port is required
+    let mut missing_port = crate::epee::Section::new();
+    let mut addr = crate::epee::Section::new();
+    addr.insert("m_ip", crate::epee::Value::U32(1));
+    missing_port.insert("addr", crate::epee::Value::Section(addr));
+    missing_port.insert("type", crate::epee::Value::U8(1));
+    let bytes = crate::epee::to_bytes(&missing_port).unwrap();
+    assert!(crate::epee::from_bytes::<NetworkAddress>(&bytes, &Default::default()).is_err());
+
+    // Anonymity networks: { addr: { host, port }, type: 4 }
+    let host = format!("{}.onion", "a".repeat(56));
+    let tor = NetworkAddress::tor(host.clone(), 18083).unwrap();
+    assert_eq!(NetZone::Tor, tor.get_zone());
+    let bytes = crate::epee::to_bytes(&tor).unwrap();
+    let mut expected = vec![
+        8, 4, b'a', b'd', b'd', b'r', 12, 8, 4, b'h', b'o', b's', b't', 10,
+    ];
+    expected.push((host.len() as u8) << 2);
+    expected.extend_from_slice(host.as_bytes());
+    expected.extend_from_slice(&[4, b'p', b'o', b'r', b't', 7, 0xa3, 0x46]);
+    expected.extend_from_slice(&[4, b't', b'y', b'p', b'e', 8, 4]);
+    assert_eq!(expected, bytes[9..]);
+    assert_eq!(
+        tor,
+        crate::epee::from_bytes(&bytes, &Default::default()).unwrap()
+    );
+
+    let i2p: NetworkAddress =
+        serde_json::from_str(&format!(r#""{}.b32.i2p:0""#, "b".repeat(52))).unwrap();
+    assert_eq!(NetZone::I2p, i2p.get_zone());
+    assert_eq!(NetworkAddressTypeEnum::I2P, i2p.addr_type());
+    let bytes = crate::epee::to_bytes(&i2p).unwrap();
+    assert_eq!(
+        i2p,
+        crate::epee::from_bytes(&bytes, &Default::default()).unwrap()
+    );
+
+    // The host must be of the network of the type
+    assert!(NetworkAddress::tor("node.b32.i2p", 1).is_err());
+    assert!(NetworkAddress::i2p(format!("{}.b32.i2p", "b".repeat(53)), 1).is_err());
+    assert!("example.com:18080".parse::<NetworkAddress>().is_err());
 }
 
-impl AllFieldsNetworkAddress {
-    fn try_into_network_address(self, ty: u8) -> Option<NetworkAddress> {
-        println!(
-            "DEBUG:  AllFieldsNetworkAddress  try_into_network_address: {:?} ",
-            ty
-        );
-
-        Some(match ty {
-            1 => NetworkAddress::from(SocketAddrV4::new(Ipv4Addr::from(self.m_ip?), self.m_port?)),
-            2 => NetworkAddress::from(SocketAddrV6::new(
-                Ipv6Addr::from(self.addr?),
-                self.m_port?,
-                0,
-                0,
-            )),
-            _ => return None,
-        })
+#[test]
+fn network_address_class() {
+    for (address, class) in [
+        ("0.0.0.0:18080", AddressClass::Unspecified),
+        ("127.0.0.1:18080", AddressClass::Loopback),
+        ("10.1.2.3:18080", AddressClass::Private),
+        ("172.16.0.1:18080", AddressClass::Private),
+        ("192.168.1.1:18080", AddressClass::Private),
+        ("169.254.0.1:18080", AddressClass::LinkLocal),
+        ("224.0.0.1:18080", AddressClass::Multicast),
+        ("203.0.113.5:18080", AddressClass::Documentation),
+        ("100.64.0.1:18080", AddressClass::Reserved),
+        ("255.255.255.255:18080", AddressClass::Reserved),
+        ("185.240.242.36:18080", AddressClass::Public),
+        ("[::]:18080", AddressClass::Unspecified),
+        ("[::1]:18080", AddressClass::Loopback),
+        ("[fd00::1]:18080", AddressClass::Private),
+        ("[fe80::1]:18080", AddressClass::LinkLocal),
+        ("[ff02::1]:18080", AddressClass::Multicast),
+        ("[2001:db8::1]:18080", AddressClass::Documentation),
+        ("[::ffff:192.168.1.1]:18080", AddressClass::Private),
+        ("[::ffff:65.144.135.125]:18080", AddressClass::Public),
+        ("[2a01:4f8::1]:18080", AddressClass::Public),
+    ] {
+        let a: NetworkAddress = address.parse().unwrap();
+        assert_eq!(class, a.classify(), "{}", address);
+        assert_eq!(class == AddressClass::Public, a.is_publicly_routable());
+        assert_eq!(NetZone::Public, a.get_zone());
     }
+
+    assert!("127.0.0.2:1"
+        .parse::<NetworkAddress>()
+        .unwrap()
+        .is_loopback());
+    assert!("[fe80::1]:1".parse::<NetworkAddress>().unwrap().is_local());
+    assert!(!"8.8.8.8:0"
+        .parse::<NetworkAddress>()
+        .unwrap()
+        .is_publicly_routable());
+
+    let tor = NetworkAddress::tor(format!("{}.onion", "a".repeat(56)), 18083).unwrap();
+    assert_eq!(AddressClass::Overlay, tor.classify());
+    assert!(!tor.is_local() && !tor.is_publicly_routable());
 }
@@ -0,0 +1,362 @@
+// Node
+//
+// State of our node that is sent to the peers: the peer id, the support
+// flags, the core sync data and the peers that we know. It answers the
+// timed syncs, the pings and the support flags requests of the node, so
+// the connection is not dropped after the handshake. It also keeps the
+// flags of the node, so the features can be checked before they are
+// used
+//
+
+use std::time::Duration;
+
+use futures::{Sink, SinkExt, Stream, StreamExt};
+
+use crate::dispatcher::Dispatcher;
+use crate::epee::{from_bytes, to_bytes, DecodeLimits};
+use crate::error::ConnectNodeError;
+use crate::protocol::{
+    Command, LevinFlags, NodeData, P2PMessage, PayloadType, PeerListEntryBase, PingRequest,
+    PingResponse, SupportFlags, SupportFlagsRequest, SupportFlagsResponse, TimedSyncRequest,
+    TimedSyncResponse, PING_OK_RESPONSE_STATUS_TEXT,
+};
+
+// Same value as P2P_DEFAULT_HANDSHAKE_INTERVAL in monerod
+pub const DEFAULT_TIMED_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+// Same value as P2P_DEFAULT_PEERS_IN_HANDSHAKE in monerod
+pub const MAX_PEERS_IN_TIMED_SYNC: usize = 250;
+// Same value as P2P_LOCAL_WHITE_PEERLIST_LIMIT in monerod
+pub const MAX_KNOWN_PEERS: usize = 1000;
+// Same value as P2P_DEFAULT_PING_CONNECTION_TIMEOUT in monerod
+pub const PING_TIMEOUT: Duration = Duration::from_millis(2000);
+
+#[derive(Debug, Default)]
+pub struct NodeState {
+    peer_id: u64,
+    support_flags: SupportFlags,
+    // Flags of the node. None until they are received
+    remote_support_flags: Option<SupportFlags>,
+    payload_data: PayloadType,
+    // Routable peers, the most recent first
+    peers: Vec<PeerListEntryBase>,
+    // Last core sync data of the node
+    remote_payload_data: Option<PayloadType>,
+}
+
+impl NodeState {
+    // Node data and core sync data of our handshake request
+    pub fn new(in_node_data: &NodeData, in_payload_data: PayloadType) -> Self {
+        NodeState {
+            peer_id: in_node_data.peer_id,
+            support_flags: in_node_data.support_flags,
+            payload_data: in_payload_data,
+            ..Default::default()
+        }
+    }
+
+    pub fn peer_id(&self) -> u64 {
+        self.peer_id
+    }
+
+    pub fn support_flags(&self) -> SupportFlags {
+        self.support_flags
+    }
+
+    pub fn remote_support_flags(&self) -> Option<SupportFlags> {
+        self.remote_support_flags
+    }
+
+    pub fn set_remote_support_flags(&mut self, in_flags: SupportFlags) {
+        self.remote_support_flags = Some(in_flags);
+    }
+
+    // Features supported by both sides
+    pub fn negotiated_flags(&self) -> SupportFlags {
+        self.support_flags & self.remote_support_flags.unwrap_or_default()
+    }
+
+    pub fn payload_data(&self) -> &PayloadType {
+        &self.payload_data
+    }
+
+    pub fn set_payload_data(&mut self, in_payload_data: PayloadType) {
+        self.payload_data = in_payload_data;
+    }
+
+    pub fn remote_payload_data(&self) -> Option<&PayloadType> {
+        self.remote_payload_data.as_ref()
+    }
+
+    pub fn set_remote_payload_data(&mut self, in_payload_data: PayloadType) {
+        self.remote_payload_data = Some(in_payload_data);
+    }
+
+    pub fn peers(&self) -> &[PeerListEntryBase] {
+        &self.peers
+    }
+
+    // Only the routable peers are kept. A known address is replaced
+    pub fn add_peers(&mut self, in_peers: impl IntoIterator<Item = PeerListEntryBase>) {
+        for p in in_peers {
+            if !p.adr.is_publicly_routable() {
+                continue;
+            }
+
+            self.peers.retain(|known| known.adr != p.adr);
+            self.peers.insert(0, p);
+        }
+
+        self.peers.truncate(MAX_KNOWN_PEERS);
+    }
+
+    pub fn timed_sync_request(&self) -> TimedSyncRequest {
+        TimedSyncRequest {
+            payload_data: self.payload_data.clone(),
+        }
+    }
+
+    pub fn timed_sync_response(&self) -> TimedSyncResponse {
+        TimedSyncResponse {
+            local_peerlist_new: self
+                .peers
+                .iter()
+                .take(MAX_PEERS_IN_TIMED_SYNC)
+                .cloned()
+                .collect(),
+            payload_data: self.payload_data.clone(),
+        }
+    }
+
+    // Keep the core sync data of the node and build the response
+    pub fn answer_timed_sync(
+        &mut self,
+        in_request: &TimedSyncRequest,
+    ) -> Result<P2PMessage, ConnectNodeError> {
+        self.remote_payload_data = Some(in_request.payload_data.clone());
+
+        P2PMessage::response(Command::TimedSync, to_bytes(&self.timed_sync_response())?)
+    }
+
+    // The request has no fields, so it is not decoded
+    pub fn answer_ping(&self) -> Result<P2PMessage, ConnectNodeError> {
+        let response = PingResponse {
+            peer_id: self.peer_id,
+            status: PING_OK_RESPONSE_STATUS_TEXT.to_string(),
+        };
+
+        P2PMessage::response(Command::Ping, to_bytes(&response)?)
+    }
+
+    // The request has no fields, so it is not decoded
+    pub fn answer_support_flags(&self) -> Result<P2PMessage, ConnectNodeError> {
+        let response = SupportFlagsResponse {
+            support_flags: self.support_flags,
+        };
+
+        P2PMessage::response(Command::SupportFlags, to_bytes(&response)?)
+    }
+
+    // Response of the node to our timed sync
+    pub fn process_timed_sync(
+        &mut self,
+        in_response: &P2PMessage,
+        in_limits: &DecodeLimits,
+    ) -> Result<(), ConnectNodeError> {
+        let response: TimedSyncResponse = from_bytes(&in_response.buffer, in_limits)?;
+        self.remote_payload_data = Some(response.payload_data);
+        self.add_peers(response.local_peerlist_new);
+
+        Ok(())
+    }
+}
+
+// Send a ping and wait for its response, without handshake. The
+// other messages received in the meantime are dropped
+pub async fn ping<R, W>(
+    in_reader: &mut R,
+    in_writer: &mut W,
+    in_limits: &DecodeLimits,
+) -> Result<PingResponse, ConnectNodeError>
+where
+    R: Stream<Item = Result<P2PMessage, ConnectNodeError>> + Unpin,
+    W: Sink<P2PMessage, Error = ConnectNodeError> + Unpin,
+{
+    let mut request = P2PMessage::new_command(Command::Ping)?;
+    request.buffer = to_bytes(&PingRequest {})?.into();
+    in_writer.send(request).await?;
+
+    while let Some(m) = in_reader.next().await {
+        let m = m?;
+        if m.header.command != Command::Ping || !m.header.flags.contains(LevinFlags::RESPONSE) {
+            continue;
+        }
+
+        let response: PingResponse = from_bytes(&m.buffer, in_limits)?;
+        if response.status != PING_OK_RESPONSE_STATUS_TEXT {
+            return Err(ConnectNodeError::PingFailed(response.status));
+        }
+
+        return Ok(response);
+    }
+
+    Err(ConnectNodeError::ConnectionClosed)
+}
+
+// Ask the flags of the node, i.e. when they are empty in its handshake
+pub async fn request_support_flags(
+    in_dispatcher: &Dispatcher,
+    in_limits: &DecodeLimits,
+) -> Result<SupportFlags, ConnectNodeError> {
+    let response = in_dispatcher
+        .invoke(Command::SupportFlags, to_bytes(&SupportFlagsRequest {})?)
+        .await?;
+    let response: SupportFlagsResponse = from_bytes(&response.buffer, in_limits)?;
+
+    Ok(response.support_flags)
+}
+
+#[test]
+fn node_timed_sync() {
+    use crate::protocol::LevinFlags;
+
+    let mut node = NodeState::new(
+        &NodeData::default(),
+        PayloadType {
+            current_height: 100,
+            ..Default::default()
+        },
+    );
+
+    let peer = |in_address: &str, in_id| PeerListEntryBase {
+        adr: in_address.parse().unwrap(),
+        id: in_id,
+        last_seen: 0,
+        pruning_seed: 0,
+        rpc_credits_per_hash: 0,
+        rpc_port: 0,
+    };
+    node.add_peers([
+        peer("185.240.242.36:18080", 1),
+        peer("192.168.1.10:18080", 2),
+        peer("185.240.242.36:18080", 3),
+    ]);
+    assert_eq!(1, node.peers().len());
+    assert_eq!(3, node.peers()[0].id);
+
+    // Request of the node
+    let request = TimedSyncRequest {
+        payload_data: PayloadType {
+            current_height: 3000000,
+            ..Default::default()
+        },
+    };
+    let response = node.answer_timed_sync(&request).unwrap();
+    assert_eq!(Command::TimedSync, response.header.command);
+    assert!(response.header.flags.contains(LevinFlags::RESPONSE));
+    assert_eq!(0, response.header.expected_response);
+    assert_eq!(response.buffer.len() as u64, response.header.msg_length);
+    assert_eq!(3000000, node.remote_payload_data().unwrap().current_height);
+
+    let decoded: TimedSyncResponse =
+        from_bytes(&response.buffer, &DecodeLimits::default()).unwrap();
+    assert_eq!(node.timed_sync_response(), decoded);
+    assert_eq!(100, decoded.payload_data.current_height);
+    assert_eq!(1, decoded.local_peerlist_new.len());
+
+    // Our own timed sync, answered with the same response
+    node.process_timed_sync(&response, &DecodeLimits::default())
+        .unwrap();
+    assert_eq!(100, node.remote_payload_data().unwrap().current_height);
+    assert_eq!(1, node.peers().len());
+}
+
+#[tokio::test]
+async fn node_ping() {
+    use tokio_util::codec::{FramedRead, FramedWrite};
+
+    use crate::codec::LevinCodec;
+
+    let (client, server) = tokio::io::duplex(4096);
+    let (client_read, client_write) = tokio::io::split(client);
+    let (server_read, server_write) = tokio::io::split(server);
+
+    // The node answers with its peer id
+    tokio::spawn(async move {
+        let node_data = NodeData {
+            peer_id: 0x1234,
+            ..Default::default()
+        };
+        let node = NodeState::new(&node_data, PayloadType::default());
+        let mut reader = FramedRead::new(server_read, LevinCodec::default());
+        let mut writer = FramedWrite::new(server_write, LevinCodec::default());
+
+        let request = reader.next().await.unwrap().unwrap();
+        assert_eq!(Command::Ping, request.header.command);
+        assert_eq!(1, request.header.expected_response);
+        assert_eq!([0], request.buffer[9..]);
+        writer.send(node.answer_ping().unwrap()).await.unwrap();
+    });
+
+    let mut reader = FramedRead::new(client_read, LevinCodec::default());
+    let mut writer = FramedWrite::new(client_write, LevinCodec::default());
+    let response = ping(&mut reader, &mut writer, &DecodeLimits::default())
+        .await
+        .unwrap();
+    assert_eq!(0x1234, response.peer_id);
+    assert_eq!("OK", response.status);
+
+    // The connection is closed without response
+    assert!(matches!(
+        ping(&mut reader, &mut writer, &DecodeLimits::default()).await,
+        Err(ConnectNodeError::ConnectionClosed) | Err(ConnectNodeError::Io(_))
+    ));
+}
+
+#[tokio::test]
+async fn node_support_flags() {
+    use tokio::sync::mpsc;
+
+    use crate::protocol::P2P_SUPPORT_FLAGS;
+
+    let node_data = NodeData {
+        support_flags: P2P_SUPPORT_FLAGS,
+        ..Default::default()
+    };
+    let mut node = NodeState::new(&node_data, PayloadType::default());
+    assert_eq!(SupportFlags::empty(), node.negotiated_flags());
+
+    // The node answers with its flags, one of them unknown
+    let (tx, mut rx) = mpsc::channel(8);
+    let dispatcher = Dispatcher::new(tx);
+    let remote = dispatcher.clone();
+    tokio::spawn(async move {
+        while let Some(request) = rx.recv().await {
+            assert_eq!(Command::SupportFlags, request.header.command);
+            let response = SupportFlagsResponse {
+                support_flags: SupportFlags::from(0x81),
+            };
+            let response =
+                P2PMessage::response(Command::SupportFlags, to_bytes(&response).unwrap()).unwrap();
+            assert!(remote.route(response).unwrap().is_none());
+        }
+    });
+
+    let flags = request_support_flags(&dispatcher, &DecodeLimits::default())
+        .await
+        .unwrap();
+    assert_eq!(0x81, flags.bits());
+    assert!(flags.contains(SupportFlags::FLUFFY_BLOCKS));
+
+    node.set_remote_support_flags(flags);
+    assert_eq!(SupportFlags::FLUFFY_BLOCKS, node.negotiated_flags());
+
+    // Our answer. In JSON the flags are a number
+    let response = node.answer_support_flags().unwrap();
+    let decoded: SupportFlagsResponse =
+        from_bytes(&response.buffer, &DecodeLimits::default()).unwrap();
+    assert_eq!(P2P_SUPPORT_FLAGS, decoded.support_flags);
+    assert_eq!(
+        r#"{"support_flags":1}"#,
+        serde_json::to_string(&decoded).unwrap()
+    );
+}
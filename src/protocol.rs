@@ -4,93 +4,302 @@
 // handshake protocol
 //
 
-use std::fs;
+#[cfg(test)]
+use std::net::{Ipv4Addr, SocketAddr};
+use std::{fmt, fs};
 
-use bytes::{Buf, BufMut, BytesMut};
-// For the tests
-use epee_encoding::{from_bytes, to_bytes, EpeeObject, EpeeObjectBuilder};
-use serde::{
-    de,
-    ser::{Error, SerializeSeq},
-    Deserialize, Serialize,
-};
+use bitflags::bitflags;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
 
-use crate::network::NetworkAddress;
+use crate::epee::{blob, hash, is_default};
+#[cfg(test)]
+use crate::epee::{from_bytes, to_bytes, DecodeLimits};
+use crate::{error::ConnectNodeError, network::NetworkAddress};
 
 pub const HEADER_SIZE: u8 = 33;
 
-pub const HANDSHAKE_REQUEST: u32 = 1001;
-pub const HANDSHAKE_RESPONSE: u32 = 1001;
+// Fix values of every Levin header
+pub const LEVIN_SIGNATURE: [u8; 8] = [0x01, 0x21, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01];
+pub const LEVIN_PROTOCOL_VERSION: u32 = 1;
 
-pub const TIMED_SYNC_REQUEST: u32 = 1002;
-pub const TIMED_SYNC_RESPONSE: u32 = 1002;
+bitflags! {
+    // Q: Request, S: Response, B: Begin fragment, E: End fragment
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct LevinFlags: u32 {
+        const REQUEST = 0x01;
+        const RESPONSE = 0x02;
+        const BEGIN = 0x04;
+        const END = 0x08;
+    }
+}
+
+bitflags! {
+    // Features of a node. They are in the node_data of the handshake and
+    // in the response to SUPPORT_FLAGS. The unknown bits are kept. It is
+    // a number in epee and in JSON
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(from = "u32", into = "u32")]
+    pub struct SupportFlags: u32 {
+        const FLUFFY_BLOCKS = 0x01;
+    }
+}
+
+// Same value as P2P_SUPPORT_FLAGS in monerod
+pub const P2P_SUPPORT_FLAGS: SupportFlags = SupportFlags::FLUFFY_BLOCKS;
+
+impl From<u32> for SupportFlags {
+    fn from(value: u32) -> Self {
+        SupportFlags::from_bits_retain(value)
+    }
+}
+
+impl From<SupportFlags> for u32 {
+    fn from(value: SupportFlags) -> Self {
+        value.bits()
+    }
+}
+
+// Levin commands
+// ### P2P Admin Commands: 1001 - 1007
+// ### Cryptonote Protocol Commands: 2001 - 2010
+//
+// 1004 - 1006 were removed from monerod, so they are unknown
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum Command {
+    // Used by the fragments and the noise messages
+    #[default]
+    Dummy,
+    Handshake,
+    TimedSync,
+    Ping,
+    SupportFlags,
+    NewBlock,
+    NewTransactions,
+    RequestGetObjects,
+    ResponseGetObjects,
+    RequestChain,
+    ResponseChainEntry,
+    NewFluffyBlock,
+    RequestFluffyMissingTx,
+    GetTxPoolComplement,
+    Unknown(u32),
+}
+
+impl Command {
+    // P2P Admin commands. They can be invoked
+    pub fn is_admin(&self) -> bool {
+        (1000..=1999).contains(&u32::from(*self))
+    }
+
+    // Cryptonote protocol commands. They are always notifications
+    pub fn is_notification(&self) -> bool {
+        (2000..=2999).contains(&u32::from(*self))
+    }
+}
+
+impl From<u32> for Command {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Command::Dummy,
+            1001 => Command::Handshake,
+            1002 => Command::TimedSync,
+            1003 => Command::Ping,
+            1007 => Command::SupportFlags,
+            2001 => Command::NewBlock,
+            2002 => Command::NewTransactions,
+            2003 => Command::RequestGetObjects,
+            2004 => Command::ResponseGetObjects,
+            2006 => Command::RequestChain,
+            2007 => Command::ResponseChainEntry,
+            2008 => Command::NewFluffyBlock,
+            2009 => Command::RequestFluffyMissingTx,
+            2010 => Command::GetTxPoolComplement,
+            v => Command::Unknown(v),
+        }
+    }
+}
 
-pub const PING_REQUEST: u32 = 1003;
-pub const PING_RESPONSE: u32 = 1003;
+impl From<Command> for u32 {
+    fn from(value: Command) -> Self {
+        match value {
+            Command::Dummy => 0,
+            Command::Handshake => 1001,
+            Command::TimedSync => 1002,
+            Command::Ping => 1003,
+            Command::SupportFlags => 1007,
+            Command::NewBlock => 2001,
+            Command::NewTransactions => 2002,
+            Command::RequestGetObjects => 2003,
+            Command::ResponseGetObjects => 2004,
+            Command::RequestChain => 2006,
+            Command::ResponseChainEntry => 2007,
+            Command::NewFluffyBlock => 2008,
+            Command::RequestFluffyMissingTx => 2009,
+            Command::GetTxPoolComplement => 2010,
+            Command::Unknown(v) => v,
+        }
+    }
+}
 
-pub const STAT_INFO_REQUEST: u32 = 1004;
-pub const STAT_INFO_RESPONSE: u32 = 1004;
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Unknown(v) => write!(f, "Unknown ({})", v),
+            c => write!(f, "{:?} ({})", c, u32::from(*c)),
+        }
+    }
+}
 
-pub const NETWORK_STATE_REQUEST: u32 = 1005;
-pub const NETWORK_STATE_RESPONSE: u32 = 1005;
+// Return codes. monerod handlers return 1 when the invoke succeeds
+// and the negative values are the Levin errors
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum ReturnCode {
+    #[default]
+    Ok,
+    Success,
+    ErrorConnection,
+    ErrorConnectionNotFound,
+    ErrorConnectionDestroyed,
+    ErrorConnectionTimedOut,
+    ErrorConnectionNoDuplexProtocol,
+    ErrorConnectionHandlerNotDefined,
+    ErrorFormat,
+    Other(i32),
+}
 
-pub const PEER_ID_REQUEST: u32 = 1006;
-pub const PEER_ID_RESPONSE: u32 = 1006;
+impl ReturnCode {
+    pub fn is_error(&self) -> bool {
+        i32::from(*self) < 0
+    }
+}
 
-pub const SUPPORT_FLAGS_REQUEST: u32 = 1007;
-pub const SUPPORT_FLAGS_RESPONSE: u32 = 1007;
+impl From<i32> for ReturnCode {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => ReturnCode::Ok,
+            1 => ReturnCode::Success,
+            -1 => ReturnCode::ErrorConnection,
+            -2 => ReturnCode::ErrorConnectionNotFound,
+            -3 => ReturnCode::ErrorConnectionDestroyed,
+            -4 => ReturnCode::ErrorConnectionTimedOut,
+            -5 => ReturnCode::ErrorConnectionNoDuplexProtocol,
+            -6 => ReturnCode::ErrorConnectionHandlerNotDefined,
+            -7 => ReturnCode::ErrorFormat,
+            v => ReturnCode::Other(v),
+        }
+    }
+}
 
-// At the moment, these are not implemented
-// ### Cryptonote Protocol Commands
+impl From<ReturnCode> for i32 {
+    fn from(value: ReturnCode) -> Self {
+        match value {
+            ReturnCode::Ok => 0,
+            ReturnCode::Success => 1,
+            ReturnCode::ErrorConnection => -1,
+            ReturnCode::ErrorConnectionNotFound => -2,
+            ReturnCode::ErrorConnectionDestroyed => -3,
+            ReturnCode::ErrorConnectionTimedOut => -4,
+            ReturnCode::ErrorConnectionNoDuplexProtocol => -5,
+            ReturnCode::ErrorConnectionHandlerNotDefined => -6,
+            ReturnCode::ErrorFormat => -7,
+            ReturnCode::Other(v) => v,
+        }
+    }
+}
 
-// #### (`2001` Notification) New Block
-// #### (`2002` Notification) New Transactions
-// #### (`2003` Notification) Request Get Objects
-// #### (`2004` Notification) Response Get Objects
-// #### (`2006` Notification) Request Chain
-// #### (`2007` Notification) Response Chain Entry
-// #### (`2008` Notification) New Fluffy Block
-// #### (`2009` Notification) Request Fluffy Missing TX
+impl fmt::Display for ReturnCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReturnCode::Other(v) => write!(f, "Other ({})", v),
+            r => write!(f, "{:?} ({})", r, i32::from(*r)),
+        }
+    }
+}
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct P2PMessage {
     pub header: Header,
-    pub buffer: Vec<u8>,
+    // Payload. It shares the memory of the received frame
+    pub buffer: Bytes,
 }
 
 impl P2PMessage {
     pub fn new() -> Self {
         P2PMessage {
             header: Header::new(),
-            buffer: Vec::new(),
+            buffer: Bytes::new(),
         }
     }
 
-    pub fn new_command(in_command: u32) -> Self {
-        P2PMessage {
-            header: Header::new_command(in_command),
-            buffer: Vec::new(),
-        }
+    pub fn new_command(in_command: Command) -> Result<Self, ConnectNodeError> {
+        Ok(P2PMessage {
+            header: Header::new_command(in_command)?,
+            buffer: Bytes::new(),
+        })
     }
 
-    pub fn from_bytes(&mut self, mut in_buffer: &[u8]) {
-        // To check that it moves the Cursor (consuming the bytes)
-        self.header.from_bytes(&in_buffer);
+    // Request to the node, with the payload
+    pub fn request(
+        in_command: Command,
+        in_payload: impl Into<Bytes>,
+    ) -> Result<Self, ConnectNodeError> {
+        let buffer: Bytes = in_payload.into();
+        let mut header = Header::new_command(in_command)?;
+        header.msg_length = buffer.len() as u64;
 
-        self.buffer = in_buffer[..].to_vec();
+        Ok(P2PMessage { header, buffer })
     }
 
-    pub fn to_bytes(&self) -> Vec<u8> {
+    pub fn notify(
+        in_command: Command,
+        in_payload: impl Into<Bytes>,
+    ) -> Result<Self, ConnectNodeError> {
+        let buffer: Bytes = in_payload.into();
+        let mut header = Header::new_notify(in_command)?;
+        header.msg_length = buffer.len() as u64;
+
+        Ok(P2PMessage { header, buffer })
+    }
+
+    // Response to a request of the node
+    pub fn response(
+        in_command: Command,
+        in_payload: impl Into<Bytes>,
+    ) -> Result<Self, ConnectNodeError> {
+        let buffer: Bytes = in_payload.into();
+        let mut header = Header::new_response(in_command)?;
+        header.msg_length = buffer.len() as u64;
+
+        Ok(P2PMessage { header, buffer })
+    }
+
+    // The payload is not copied, it is a slice of in_buffer
+    pub fn from_bytes(in_buffer: Bytes) -> Result<Self, ConnectNodeError> {
+        Ok(P2PMessage {
+            header: Header::from_bytes(&in_buffer)?,
+            buffer: in_buffer.slice(HEADER_SIZE as usize..),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
         let mut output_buffer = BytesMut::with_capacity(HEADER_SIZE as usize + self.buffer.len());
+        self.write_to(&mut output_buffer);
 
-        output_buffer.extend_from_slice(&self.header.to_bytes());
-        output_buffer.extend_from_slice(&self.buffer);
+        output_buffer.freeze()
+    }
 
-        output_buffer.to_vec()
+    // Append header + payload to in_buffer
+    pub fn write_to(&self, in_buffer: &mut BytesMut) {
+        in_buffer.reserve(HEADER_SIZE as usize + self.buffer.len());
+        self.header.write_to(in_buffer);
+        in_buffer.extend_from_slice(&self.buffer);
     }
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Header {
     // Fix value
     // 0x01     |      0x21     |      0x01     |      0x01     |
@@ -98,10 +307,10 @@ pub struct Header {
     pub signature: [u8; 8],
     pub msg_length: u64,
     pub expected_response: u8,
-    pub command: u32,
-    pub return_code: u32,
+    pub command: Command,
+    pub return_code: ReturnCode,
     // Only Less Significant nibble (4bits)
-    pub flags: u32,
+    pub flags: LevinFlags,
     // Fix value 0x01
     pub version: u32,
 }
@@ -109,14 +318,17 @@ pub struct Header {
 impl Header {
     pub fn new() -> Self {
         Header {
-            signature: [0x01, 0x21, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01],
-            version: 0x01 as u32,
+            signature: LEVIN_SIGNATURE,
+            version: LEVIN_PROTOCOL_VERSION,
             ..Default::default()
         }
     }
-    pub fn new_command(in_command: u32) -> Self {
-        if in_command < 1000 || in_command > 1999 {
-            return Self::new();
+    pub fn new_command(in_command: Command) -> Result<Self, ConnectNodeError> {
+        if !in_command.is_admin() {
+            return Err(ConnectNodeError::InvalidCommand {
+                command: in_command,
+                kind: "request",
+            });
         }
 
         let mut header = Header::new();
@@ -124,73 +336,173 @@ impl Header {
         header.command = in_command;
         header.expected_response = 1;
         // Request. Q is set
-        header.flags = 1 as u32;
+        header.flags = LevinFlags::REQUEST;
+
+        Ok(header)
+    }
+
+    // Response to a request of the node. monerod returns 1 on success
+    pub fn new_response(in_command: Command) -> Result<Self, ConnectNodeError> {
+        if !in_command.is_admin() {
+            return Err(ConnectNodeError::InvalidCommand {
+                command: in_command,
+                kind: "response",
+            });
+        }
 
-        header
+        let mut header = Header::new();
+
+        header.command = in_command;
+        header.expected_response = 0;
+        header.return_code = ReturnCode::Success;
+        // Response. S is set
+        header.flags = LevinFlags::RESPONSE;
+
+        Ok(header)
     }
 
-    pub fn from_bytes(&mut self, mut in_buffer: &[u8]) {
-        self.signature.clone_from_slice(&in_buffer[0..8]);
+    // The notifications do not have a response
+    pub fn new_notify(in_command: Command) -> Result<Self, ConnectNodeError> {
+        if !in_command.is_notification() {
+            return Err(ConnectNodeError::InvalidCommand {
+                command: in_command,
+                kind: "notification",
+            });
+        }
+
+        let mut header = Header::new();
+
+        header.command = in_command;
+        header.expected_response = 0;
+        // Sent as a request. Q is set
+        header.flags = LevinFlags::REQUEST;
+
+        Ok(header)
+    }
+
+    pub fn from_bytes(mut in_buffer: &[u8]) -> Result<Self, ConnectNodeError> {
+        if in_buffer.len() < HEADER_SIZE as usize {
+            return Err(ConnectNodeError::HeaderTooShort(in_buffer.len()));
+        }
+
+        let mut header = Header::new();
+
+        header.signature.copy_from_slice(&in_buffer[0..8]);
 
         in_buffer.advance(8);
 
-        self.msg_length = in_buffer.get_u64_le();
-        self.expected_response = in_buffer.get_u8();
-        self.command = in_buffer.get_u32_le();
-        self.return_code = in_buffer.get_u32_le();
-        self.flags = in_buffer.get_u32_le();
-        self.version = in_buffer.get_u32_le();
+        header.msg_length = in_buffer.get_u64_le();
+        header.expected_response = in_buffer.get_u8();
+        header.command = Command::from(in_buffer.get_u32_le());
+        header.return_code = ReturnCode::from(in_buffer.get_i32_le());
+        header.flags = LevinFlags::from_bits_retain(in_buffer.get_u32_le());
+        header.version = in_buffer.get_u32_le();
+
+        Ok(header)
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut buffer = BytesMut::with_capacity(HEADER_SIZE as usize);
+        let mut buffer = Vec::with_capacity(HEADER_SIZE as usize);
+        self.write_to(&mut buffer);
 
-        buffer.extend_from_slice(&self.signature);
-        buffer.put_u64_le(self.msg_length);
-        buffer.put_u8(self.expected_response);
-        buffer.put_u32_le(self.command);
-        buffer.put_u32_le(self.return_code);
-        buffer.put_u32_le(self.flags);
-        buffer.put_u32_le(self.version);
+        buffer
+    }
 
-        buffer.to_vec()
+    pub fn write_to<B: BufMut>(&self, in_buffer: &mut B) {
+        in_buffer.put_slice(&self.signature);
+        in_buffer.put_u64_le(self.msg_length);
+        in_buffer.put_u8(self.expected_response);
+        in_buffer.put_u32_le(u32::from(self.command));
+        in_buffer.put_i32_le(i32::from(self.return_code));
+        in_buffer.put_u32_le(self.flags.bits());
+        in_buffer.put_u32_le(self.version);
     }
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq, EpeeObject)]
+// struct basic_node_data. monerod writes the fields in alphabetical
+// order and leaves out the optional ones when they are 0
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NodeData {
-    // It looks like it can be v4 or v5
-    pub network_id: [u8; 16], //Uuid,
     pub my_port: u32,
-    pub rpc_port: u16,
-    pub rpc_credits_per_hash: u32,
+    // It looks like it can be v4 or v5
+    #[serde(with = "network_id_format")]
+    pub network_id: [u8; 16],
     pub peer_id: u64,
-    // It looks like it should be 1
-    pub support_flags: u32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rpc_credits_per_hash: u32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rpc_port: u16,
+    // If they are empty, monerod asks them with SUPPORT_FLAGS
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub support_flags: SupportFlags,
+}
+
+// The network id is written as an UUID in the config files. A list of
+// bytes is also accepted. In epee, it is a blob of 16 bytes
+mod network_id_format {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use uuid::Uuid;
+
+    use crate::epee::hash;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NetworkId {
+        Uuid(Uuid),
+        Bytes([u8; 16]),
+    }
+
+    pub fn serialize<S: Serializer>(in_id: &[u8; 16], serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return hash::serialize(in_id, serializer);
+        }
+        Uuid::from_bytes(*in_id).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 16], D::Error> {
+        if !deserializer.is_human_readable() {
+            return hash::deserialize(deserializer);
+        }
+        Ok(match NetworkId::deserialize(deserializer)? {
+            NetworkId::Uuid(u) => *u.as_bytes(),
+            NetworkId::Bytes(b) => b,
+        })
+    }
 }
 
 // struct CORE_SYNC_DATA from cryptonote
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq, EpeeObject)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PayloadType {
-    pub current_height: u64,
     pub cumulative_difficulty: u64,
+    // Old nodes do not send it, but monerod always writes it
+    #[serde(default)]
     pub cumulative_difficulty_top64: u64,
+    pub current_height: u64,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub pruning_seed: u32,
+    #[serde(with = "hash")]
     pub top_id: [u8; 32],
+    #[serde(default, skip_serializing_if = "is_default")]
     pub top_version: u8,
-    pub pruning_seed: u32,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, EpeeObject)]
+// Alphabetical order, as monerod. The optional fields are not written
+// when they are 0
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PeerListEntryBase {
     pub adr: NetworkAddress,
     pub id: u64,
+    #[serde(default, skip_serializing_if = "is_default")]
     pub last_seen: i64,
+    #[serde(default, skip_serializing_if = "is_default")]
     pub pruning_seed: u32,
-    pub rpc_port: u16,
+    #[serde(default, skip_serializing_if = "is_default")]
     pub rpc_credits_per_hash: u32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rpc_port: u16,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq, EpeeObject)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HandshakeRequest {
     // It seems it is not needed
     // #[epee_flatten]
@@ -222,8 +534,7 @@ impl HandshakeRequest {
         self.node_data.rpc_credits_per_hash = 0;
         // RANDOM
         self.node_data.peer_id = 0x87654321;
-        // Fails, if empty
-        self.node_data.support_flags = 1;
+        self.node_data.support_flags = P2P_SUPPORT_FLAGS;
     }
 
     pub fn set_payload_data(&mut self) {
@@ -231,26 +542,23 @@ impl HandshakeRequest {
         self.payload_data.pruning_seed = 0;
     }
 
-    pub fn load_from_file(&mut self, in_filename: impl AsRef<str>) {
-        let tmp_buffer: String = match fs::read_to_string(in_filename.as_ref()) {
-            Ok(b) => b,
-            Err(e) => {
-                println!("ERROR: Reading config file: {}", e);
-                return;
-            }
-        };
+    pub fn load_from_file(&mut self, in_filename: impl AsRef<str>) -> Result<(), ConnectNodeError> {
+        let tmp_buffer: String = fs::read_to_string(in_filename.as_ref())?;
 
-        let request: HandshakeRequest = serde_json::from_str(&tmp_buffer).unwrap();
+        let request: HandshakeRequest = serde_json::from_str(&tmp_buffer)?;
 
         *self = request;
+
+        Ok(())
     }
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq, EpeeObject)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HandshakeResponse {
+    #[serde(default)]
+    pub local_peerlist_new: Vec<PeerListEntryBase>,
     pub node_data: NodeData,
     pub payload_data: PayloadType,
-    pub local_peerlist_new: Vec<PeerListEntryBase>,
 }
 
 impl HandshakeResponse {
@@ -259,6 +567,138 @@ impl HandshakeResponse {
             ..Default::default()
         }
     }
+
+    // Peers that can be stored or dialed. The rest of the addresses
+    // are local, reserved or of other networks
+    pub fn routable_peers(&self) -> impl Iterator<Item = &PeerListEntryBase> {
+        self.local_peerlist_new
+            .iter()
+            .filter(|p| p.adr.is_publicly_routable())
+    }
+}
+
+// COMMAND_TIMED_SYNC. Sent by both sides to keep the connection
+// alive and to exchange the height and the peers
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimedSyncRequest {
+    pub payload_data: PayloadType,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimedSyncResponse {
+    #[serde(default)]
+    pub local_peerlist_new: Vec<PeerListEntryBase>,
+    pub payload_data: PayloadType,
+}
+
+// Same value as PING_OK_RESPONSE_STATUS_TEXT in monerod
+pub const PING_OK_RESPONSE_STATUS_TEXT: &str = "OK";
+
+// COMMAND_PING. The request has no fields. It can be sent before the
+// handshake, as monerod does to check that a peer is reachable
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PingRequest {}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PingResponse {
+    pub peer_id: u64,
+    pub status: String,
+}
+
+// COMMAND_REQUEST_SUPPORT_FLAGS. The request has no fields
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SupportFlagsRequest {}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SupportFlagsResponse {
+    pub support_flags: SupportFlags,
+}
+
+// NOTIFY_NEW_TRANSACTIONS. The transactions relayed by the node. The
+// padding hides the size of the transactions and dandelionpp_fluff is
+// false while they are in the stem phase of Dandelion++. monerod leaves
+// it out when it is true (KV_SERIALIZE_OPT(dandelionpp_fluff, true))
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewTransactions {
+    #[serde(
+        rename = "_",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        with = "blob"
+    )]
+    pub padding: Vec<u8>,
+    #[serde(default = "default_fluff", skip_serializing_if = "is_fluff")]
+    pub dandelionpp_fluff: bool,
+    #[serde(default, with = "blob::list")]
+    pub txs: Vec<Vec<u8>>,
+}
+
+fn default_fluff() -> bool {
+    true
+}
+
+fn is_fluff(in_value: &bool) -> bool {
+    *in_value
+}
+
+impl Default for NewTransactions {
+    fn default() -> Self {
+        NewTransactions {
+            padding: Vec::new(),
+            dandelionpp_fluff: default_fluff(),
+            txs: Vec::new(),
+        }
+    }
+}
+
+#[test]
+fn levin_header() {
+    let mut header = Header::new_command(Command::SupportFlags).unwrap();
+    header.return_code = ReturnCode::ErrorFormat;
+    header.flags |= LevinFlags::END;
+
+    let bytes = header.to_bytes();
+    assert_eq!([0xf9, 0xff, 0xff, 0xff], bytes[21..25]);
+    assert_eq!(header, Header::from_bytes(&bytes).unwrap());
+
+    let mut unknown = Header::new();
+    unknown.command = Command::from(3001);
+    unknown.return_code = ReturnCode::from(-100);
+    unknown.flags = LevinFlags::from_bits_retain(0x10);
+    assert_eq!(unknown, Header::from_bytes(&unknown.to_bytes()).unwrap());
+
+    assert_eq!("NewTransactions (2002)", Command::from(2002).to_string());
+
+    let notify = P2PMessage::notify(Command::NewTransactions, vec![0; 10]).unwrap();
+    assert_eq!(0, notify.header.expected_response);
+    assert_eq!(LevinFlags::REQUEST, notify.header.flags);
+    assert_eq!(10, notify.header.msg_length);
+
+    // The payload is not copied
+    let frame = notify.to_bytes();
+    let message = P2PMessage::from_bytes(frame.clone()).unwrap();
+    assert_eq!(notify.header, message.header);
+    assert_eq!(
+        frame[HEADER_SIZE as usize..].as_ptr(),
+        message.buffer.as_ptr()
+    );
+
+    assert!(matches!(
+        Header::new_notify(Command::Ping),
+        Err(ConnectNodeError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        Header::new_command(Command::NewTransactions),
+        Err(ConnectNodeError::InvalidCommand { .. })
+    ));
+    assert!(matches!(
+        Header::new_response(Command::NewTransactions),
+        Err(ConnectNodeError::InvalidCommand {
+            kind: "response",
+            ..
+        })
+    ));
+    assert_eq!("ErrorFormat (-7)", ReturnCode::from(-7).to_string());
 }
 
 #[test]
@@ -286,14 +726,13 @@ fn p2p_handshake() {
         0x75, 0x6e, 0x69, 0x6e, 0x67, 0x5f, 0x73, 0x65, 0x65, 0x64, 0x6, 0x0, 0x0, 0x0, 0x0,
     ];
 
-    let request: HandshakeRequest = match from_bytes(request_bytes.as_slice()) {
-        Ok(r) => r,
-        Err(e) => {
-            println!("ERROR: {}", e);
-            assert!(false);
-            return;
-        }
-    };
+    let request: HandshakeRequest =
+        match from_bytes(request_bytes.as_slice(), &DecodeLimits::default()) {
+            Ok(r) => r,
+            Err(e) => {
+                panic!("ERROR: {}", e);
+            }
+        };
 
     // println!("{:x?}", request);
 
@@ -304,7 +743,7 @@ fn p2p_handshake() {
             0xA1, 0x11,
         ],
         peer_id: 0x87654321,
-        support_flags: 1,
+        support_flags: SupportFlags::FLUFFY_BLOCKS,
         rpc_port: 0,
         rpc_credits_per_hash: 0,
     };
@@ -325,750 +764,747 @@ fn p2p_handshake() {
     assert_eq!(basic_node_data, request.node_data);
     assert_eq!(core_sync_data, request.payload_data);
 
-    // Check that if we encode, we obtain the same result
+    // monerod writes the fields in alphabetical order and leaves out
+    // rpc_port, rpc_credits_per_hash, top_version and pruning_seed
+    let monerod_bytes = [
+        0x1, 0x11, 0x1, 0x1, 0x1, 0x1, 0x2, 0x1, 0x1, 0x8, 0x9, 0x6e, 0x6f, 0x64, 0x65, 0x5f, 0x64,
+        0x61, 0x74, 0x61, 0xc, 0x10, 0x7, 0x6d, 0x79, 0x5f, 0x70, 0x6f, 0x72, 0x74, 0x6, 0x0, 0x0,
+        0x0, 0x0, 0xa, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0xa, 0x40, 0x12,
+        0x30, 0xf1, 0x71, 0x61, 0x4, 0x41, 0x61, 0x17, 0x31, 0x0, 0x82, 0x16, 0xa1, 0xa1, 0x11,
+        0x7, 0x70, 0x65, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x5, 0x21, 0x43, 0x65, 0x87, 0x0, 0x0, 0x0,
+        0x0, 0xd, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x5f, 0x66, 0x6c, 0x61, 0x67, 0x73,
+        0x6, 0x1, 0x0, 0x0, 0x0, 0xc, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x5f, 0x64, 0x61,
+        0x74, 0x61, 0xc, 0x10, 0x15, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65,
+        0x5f, 0x64, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74, 0x79, 0x5, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1b, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x5f,
+        0x64, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74, 0x79, 0x5f, 0x74, 0x6f, 0x70, 0x36,
+        0x34, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xe, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e,
+        0x74, 0x5f, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x6, 0x74, 0x6f, 0x70, 0x5f, 0x69, 0x64, 0xa, 0x80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ];
     let encoded_bytes = to_bytes(&request).unwrap();
 
-    assert_eq!(encoded_bytes, request_bytes);
+    assert_eq!(encoded_bytes, monerod_bytes);
+    assert_eq!(
+        request,
+        from_bytes(&monerod_bytes, &DecodeLimits::default()).unwrap()
+    );
 }
 
-// NOTE: This test case causes a stack overflow in the epee_encoding library
+// Handshake response of a mainnet node, with 250 peers
+#[cfg(test)]
+const HANDSHAKE_RESPONSE_BYTES: &[u8] = &[
+    1, 17, 1, 1, 1, 1, 2, 1, 1, 12, 18, 108, 111, 99, 97, 108, 95, 112, 101, 101, 114, 108, 105,
+    115, 116, 95, 110, 101, 119, 140, 233, 3, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 107, 200, 125, 246, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 136, 104, 95, 61, 247, 215, 186, 88, 8, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 184, 166, 147, 112, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 104, 35, 236,
+    206, 255, 98, 40, 247, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 94, 23, 147, 238, 6, 109, 95, 112, 111, 114, 116, 7, 64, 9, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 204, 213, 226, 217, 141, 205, 23, 171, 12, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 65,
+    144, 135, 125, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105,
+    100, 5, 142, 211, 149, 235, 68, 76, 58, 116, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169,
+    70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 85, 25,
+    198, 233, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 239, 118, 5, 143, 245, 175, 79, 79, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 128, 199, 45, 242, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 53, 123, 81, 158, 161, 118, 88, 49, 8, 114, 112, 99, 95,
+    112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 49, 12, 239, 176, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
+    112, 101, 8, 1, 2, 105, 100, 5, 231, 115, 240, 106, 33, 156, 129, 168, 12, 3, 97, 100, 114, 12,
+    8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 72, 83, 1, 29, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 163, 230, 52, 192, 29, 175,
+    184, 138, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 255, 255, 24, 46, 131, 31, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 2, 2, 105, 100, 5, 254, 139, 52, 145, 46, 192, 114, 218, 12, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    255, 255, 144, 76, 58, 247, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
+    8, 2, 2, 105, 100, 5, 22, 231, 179, 203, 218, 156, 64, 157, 12, 112, 114, 117, 110, 105, 110,
+    103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 46, 4, 27, 39, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 190, 246,
+    23, 85, 132, 101, 46, 120, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 93, 95, 228, 51, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 76, 184, 195, 205, 119, 55, 205, 223, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 89, 36, 78, 249, 6, 109, 95, 112, 111, 114, 116, 7, 40, 230, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 146, 84, 128, 27, 45, 173, 110, 233, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 159, 203, 65, 163, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 36, 140, 127, 49, 80, 143, 75, 170, 8,
+    114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 89, 163, 225, 22, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 202, 167, 239, 104, 191, 245, 206, 114, 12,
+    112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 51, 38, 53, 106, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 26, 47, 180, 164, 45,
+    11, 4, 137, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    98, 28, 252, 84, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 109, 204, 107, 31, 61, 229, 138, 61, 12, 112, 114, 117, 110, 105, 110, 103, 95,
+    115, 101, 101, 100, 6, 130, 1, 0, 0, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 35, 142, 222, 141, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 74, 23, 146, 223, 157, 219, 198, 96, 12, 112, 114, 117,
+    110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 135, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 168, 119, 134, 54, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 210, 66, 174, 96, 27, 169, 14, 141, 8, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 246, 38, 201, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 29, 41, 255, 104, 159, 199, 248,
+    152, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 5, 161, 65, 201, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 97, 17, 31, 195, 85, 103, 254, 119, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 76, 89, 170, 52, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 191, 241, 183,
+    135, 6, 109, 186, 8, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 184, 153, 84, 228, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 211, 11, 202, 189, 127, 164,
+    118, 13, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 66,
+    42, 82, 58, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 209, 217, 109, 131, 173, 212, 169, 138, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115,
+    101, 101, 100, 6, 135, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 70, 93, 166, 98, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 35, 43, 193,
+    141, 6, 220, 30, 115, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 99, 17, 41, 20, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 56, 45, 55, 79, 80, 200, 73, 54, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 130, 180, 116, 214, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 101, 87, 57, 29, 16, 210, 163, 114, 8, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 148, 163, 81, 34, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 118, 209,
+    9, 62, 203, 181, 144, 55, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 67, 85, 160, 39, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 8, 54, 90, 44, 129, 203, 30, 229, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 162, 218, 65, 223, 6, 109, 95, 112, 111, 114, 116, 7, 163, 70, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 8, 95, 132, 221, 20, 172, 44, 131, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 83, 78, 142, 101, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 53, 254, 109, 4, 238, 54, 246, 88, 12, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 18, 169, 212, 248,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 239,
+    181, 136, 45, 48, 0, 23, 111, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 138, 201, 50, 228, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 100, 143,
+    145, 170, 221, 44, 134, 40, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 96, 2, 101, 49, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 175, 162, 146, 137, 96, 124, 179, 123, 8, 114, 112, 99, 95, 112,
+    111, 114, 116, 7, 161, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 194, 55, 15, 70, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 200, 230, 46, 22, 61, 113, 146, 207, 12, 112, 114, 117, 110, 105,
+    110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
+    169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 24, 144, 51, 151, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 92, 225, 176, 105, 146, 76, 126, 246, 8,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 51, 89, 43, 165,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 227,
+    169, 44, 172, 218, 27, 134, 20, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 138, 201, 50, 11, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 77, 159, 65, 201,
+    154, 203, 137, 189, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 37, 19, 221, 165, 6, 109, 95, 112, 111, 114, 116, 7, 101, 225, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 16, 236, 61, 198, 179, 107, 122, 230, 12, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 211, 104, 25, 222, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 141, 18, 233, 140, 158, 178, 117,
+    2, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 79, 225, 232, 232, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 25, 41,
+    192, 83, 178, 152, 49, 39, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 45, 44, 224, 220, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 43, 192, 235, 1, 175, 51, 244, 209, 12, 112, 114, 117, 110, 105,
+    110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 44, 198, 160, 153, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 255, 168, 200, 48, 53, 200, 228, 34, 12, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 85, 214, 100, 187,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 237,
+    118, 70, 209, 142, 252, 124, 105, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101,
+    100, 6, 132, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 104, 52, 169, 164, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 7, 180, 60, 132, 208, 115, 38, 78, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 188,
+    214, 129, 182, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105,
+    100, 5, 6, 110, 8, 120, 108, 89, 215, 109, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 81, 79, 96, 58, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 29, 242, 12, 182, 32, 31, 164, 147, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 50, 246, 254, 202, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 200, 152, 38, 71,
+    152, 168, 148, 130, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 75, 168, 213, 32, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 89, 66, 99, 25, 23, 47, 236, 47, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 80, 217, 6, 170, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 25, 65, 171, 45, 39, 65, 82, 0, 8, 114,
+    112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 109, 95, 105, 112, 6, 24, 27, 96, 242, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 11, 5, 248, 49, 46, 9, 138, 92, 12, 112, 114, 117,
+    110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 72, 239, 241, 242, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 77, 192, 17, 161, 34, 186, 222, 123, 12, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 144, 217, 70, 139, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 144, 82, 41, 18, 67, 173, 224,
+    251, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 129, 1, 0, 0, 12, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 216, 239, 98, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 135, 151,
+    188, 127, 59, 20, 95, 161, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
+    134, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    91, 65, 190, 110, 6, 109, 95, 112, 111, 114, 116, 7, 169, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 96, 33, 127, 102, 27, 224, 111, 185, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 134, 122, 61,
+    72, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5,
+    29, 223, 119, 202, 113, 165, 81, 7, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 37, 15, 95, 252, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 219, 64,
+    229, 115, 235, 126, 213, 173, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 178, 62, 9, 149, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 151, 68, 31, 187, 63, 163, 185, 18, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 23, 115, 236, 180, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 205, 12, 26, 50, 3, 165, 53, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 71, 78, 74, 138, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 206, 121, 181, 161, 29, 110, 53, 18, 8,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 73, 216, 104,
+    205, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
+    243, 216, 53, 122, 144, 182, 239, 255, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 85, 241, 6, 106, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 249, 166, 55, 155, 51, 129, 124, 12, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 79, 199, 36, 70, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 43, 26, 130, 68, 205,
+    92, 233, 62, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10,
+    64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 73, 113, 165, 163, 6, 109, 95, 112, 111, 114, 116,
+    7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 125, 189, 171, 153, 83, 86, 245, 62,
+    12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 137, 118, 214, 52, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 28, 94, 51,
+    157, 189, 105, 178, 114, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 75, 82, 185, 108, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 76, 196, 240, 134, 116, 222, 86, 142, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
+    68, 118, 240, 19, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2,
+    105, 100, 5, 213, 192, 104, 120, 74, 119, 165, 194, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 51, 83, 179, 106, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 27, 33, 186, 12, 194, 25, 75, 31, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 178, 162, 153, 157, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 4, 66,
+    90, 148, 24, 80, 49, 170, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 92, 170, 92, 127, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 213, 45, 208, 229, 123, 172, 173, 241, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 193, 72, 32, 187, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 50, 33, 217, 128, 43, 173, 61,
+    224, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 218,
+    108, 30, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 115, 212, 228, 103, 43, 138, 93, 217, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 163, 172, 90, 168, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 135, 51, 116, 56, 95, 226, 184, 221, 12, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 255, 255, 5, 39, 91, 20, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 2, 2, 105, 100, 5, 10, 81, 175, 58, 168, 40, 36, 112, 12, 112, 114, 117, 110, 105, 110,
+    103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 45, 142, 235, 46,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 183,
+    57, 59, 58, 77, 216, 203, 0, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
+    130, 1, 0, 0, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    185, 157, 160, 119, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 115, 192, 241, 99, 119, 228, 23, 129, 20, 114, 112, 99, 95, 99, 114, 101, 100,
+    105, 116, 115, 95, 112, 101, 114, 95, 104, 97, 115, 104, 6, 0, 0, 64, 0, 8, 114, 112, 99, 95,
+    112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 5, 129, 54, 131, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 180, 136, 168, 128, 35, 8, 229, 160, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
+    185, 10, 68, 240, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2,
+    105, 100, 5, 105, 127, 240, 231, 209, 2, 20, 136, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 32, 221, 180, 229, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 107, 14, 156, 75, 92, 180, 143, 152, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 218, 88, 21, 188, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 51, 234,
+    157, 182, 229, 103, 39, 88, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 5, 161, 73, 151, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 209, 162, 121, 222, 241, 125, 142, 156, 8, 3, 97, 100, 114, 12, 8,
+    4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 162, 226, 61, 8, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 6, 64, 210, 168, 209, 134,
+    142, 135, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    104, 238, 128, 24, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 252, 199, 163, 220, 223, 220, 85, 167, 12, 112, 114, 117, 110, 105, 110, 103, 95,
+    115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 12,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 89, 147, 109, 91,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 90,
+    73, 143, 67, 50, 148, 50, 51, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 60, 225, 148, 220, 6,
+    109, 95, 112, 111, 114, 116, 7, 169, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 246, 236,
+    191, 85, 5, 65, 238, 143, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 84, 255, 235, 77, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 204, 171, 86, 75, 156, 202, 194, 17, 12, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 172, 86, 75, 188, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 102, 18, 220, 13, 62, 231, 50,
+    12, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 47, 154, 174, 191, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 142, 90,
+    23, 242, 187, 113, 45, 135, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 162, 253, 155, 86, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 115, 140, 18, 86, 209, 1, 136, 191, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 192, 42, 253, 215, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 172, 48, 83, 16, 150, 49,
+    179, 117, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 213,
+    239, 210, 144, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 191, 25, 48, 149, 62, 193, 163, 51, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 109, 95, 105, 112, 6, 213, 251, 235, 252, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
+    4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 30, 76, 94, 11, 19, 53, 222, 168, 8, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 82, 101, 246, 21, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 79, 214, 218, 34,
+    12, 184, 235, 67, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
+    6, 88, 19, 42, 245, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 252, 148, 134, 97, 173, 59, 174, 174, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 94, 63, 121, 77, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 218, 250, 149, 133, 175, 197, 220, 9, 12, 112,
+    114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 134, 1, 0, 0, 8, 3, 97, 100, 114, 12,
+    8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 49, 114, 184, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 71, 65, 104, 43, 162,
+    145, 13, 85, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    76, 185, 18, 32, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 7, 159, 72, 170, 11, 137, 53, 215, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115,
+    101, 101, 100, 6, 135, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 68, 73, 201, 40, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 172, 77, 69, 220, 58, 59, 195, 192, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
+    84, 255, 235, 77, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2,
+    105, 100, 5, 204, 171, 86, 75, 156, 202, 194, 17, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 165, 227, 34, 105, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 154, 230, 103, 180, 139, 210, 99, 101, 8, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 35, 236, 113, 185,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 49,
+    104, 224, 149, 51, 239, 29, 76, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 165, 22, 12, 133, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 246, 50, 244, 226, 219, 198, 24, 105, 12, 112, 114, 117,
+    110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 94, 156, 174, 100, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 183, 117, 141, 111, 95, 90, 58, 204, 12, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 5, 255, 100, 208, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 125, 25, 139, 251, 139, 116, 172,
+    24, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 86, 94, 156, 130, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 181, 75, 11, 73, 214, 93, 48,
+    250, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 114,
+    112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 93, 95, 230, 245,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 84,
+    157, 243, 239, 183, 35, 54, 0, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100,
+    6, 130, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12,
+    8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 86, 14, 58, 98, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 131, 145, 164, 127, 110,
+    147, 147, 166, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    52, 15, 111, 162, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 196, 112, 61, 95, 122, 150, 241, 171, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 135, 181,
+    164, 52, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100,
+    5, 52, 20, 78, 121, 22, 214, 174, 79, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 159, 69, 121, 11, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 205, 44, 250,
+    54, 193, 111, 54, 245, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128,
+    1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185,
+    241, 197, 18, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 161, 144, 210, 66, 72, 38, 182, 154, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161,
+    70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 165,
+    109, 172, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 139, 244, 62, 181, 35, 101, 190, 130, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 95, 168, 216, 7, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 113, 28, 114, 184, 37, 154, 224, 95, 8, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 138, 201, 120, 71, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 189, 245, 211,
+    35, 245, 56, 24, 89, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 91, 206, 14, 98, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 43, 84, 225, 240, 218, 249, 219, 193, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 61, 245, 153, 149, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 111, 172, 135, 36, 174, 143, 223, 112, 8,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 255, 255, 31, 28, 25, 99, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 2, 2, 105, 100, 5, 129, 160, 102, 202, 168, 75, 67, 246, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 217, 168, 137, 14, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 198, 134, 120,
+    100, 110, 70, 149, 242, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 65, 21, 134, 181, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 192, 152, 237, 245, 35, 179, 133, 92, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 82, 29, 15, 7, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 93, 238, 22, 254, 190, 156, 170,
+    53, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 100, 36,
+    215, 42, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 225, 217, 27, 239, 55, 77, 130, 218, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 221, 138, 212, 98, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 221, 115, 168, 71, 115, 113, 123, 226, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 95, 158, 72, 195, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 169, 55, 84, 253, 73,
+    235, 126, 1, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    47, 5, 146, 126, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 36, 7, 132, 185, 123, 44, 79, 9, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 199, 102, 237, 44,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 23,
+    191, 140, 80, 125, 5, 151, 123, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 69, 163, 90, 6, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 2, 215, 175, 128, 152, 162, 90, 120, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 147, 135, 136, 35, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 151, 75, 230, 186, 160, 198,
+    175, 124, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 83, 137, 41, 10, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 180, 29, 120, 16, 126, 72, 126, 35, 16,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 45, 62, 207, 10,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 131,
+    144, 70, 177, 35, 135, 112, 205, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100,
+    6, 134, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12,
+    8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 98, 225, 91, 191, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 19, 165, 172, 148, 44,
+    52, 241, 105, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    88, 212, 32, 151, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 183, 237, 176, 171, 69, 241, 107, 86, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
+    161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 51, 68, 214, 143, 6, 109, 95, 112, 111, 114, 116, 7,
+    48, 17, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 206, 248, 161, 56, 86, 125, 195, 243, 16,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 152, 67, 175, 91,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 17,
+    83, 56, 240, 65, 163, 170, 158, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100,
+    6, 130, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12,
+    8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 216, 232, 100, 178, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 167, 109, 78, 13, 99,
+    252, 5, 228, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10,
+    64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 146, 59, 0, 16, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 177, 145, 118, 10, 116, 105, 64, 90, 8,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185, 10, 68, 240,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 105,
+    127, 240, 231, 209, 2, 20, 136, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 89, 2, 142, 180, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
+    112, 101, 8, 1, 2, 105, 100, 5, 1, 21, 198, 102, 36, 134, 38, 249, 8, 114, 112, 99, 95, 112,
+    111, 114, 116, 7, 161, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 158, 140, 230, 233, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 0, 77, 233, 80, 62, 71,
+    201, 59, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 78, 106, 12, 87, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 119, 128,
+    137, 92, 63, 246, 81, 72, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 145, 255, 252, 43, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 209, 7, 199, 121, 195, 30, 91, 168, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 71, 219, 43, 129, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 165, 255, 243, 173, 120, 214, 100, 239, 12, 112, 114, 117, 110, 105, 110,
+    103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 65, 21, 137, 242,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 56,
+    81, 223, 232, 243, 53, 150, 199, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 76, 183, 153, 43, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 2, 169, 223, 201, 166, 219, 0, 108, 12, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 146, 190, 226, 170, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 184, 126, 145, 86,
+    17, 171, 106, 193, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0,
+    0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 99, 234,
+    74, 130, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 75, 225, 51, 71, 34, 147, 131, 93, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 34, 243, 233, 242, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 241, 169, 58, 47, 204, 254, 64, 194, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 37, 1, 201, 53, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 218, 61, 215, 233, 72,
+    244, 247, 85, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    100, 0, 31, 209, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 254, 223, 214, 77, 175, 11, 224, 27, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 38, 242, 201, 74, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 55, 246, 56, 7, 244, 33, 111, 185, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 178, 128, 226, 166, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 100, 250,
+    203, 11, 176, 225, 42, 24, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 45, 238, 67, 67, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 5, 230, 42, 159, 3, 83, 146, 72, 8, 114, 112, 99, 95, 112, 111, 114,
+    116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
+    6, 178, 237, 47, 93, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
+    2, 105, 100, 5, 217, 28, 236, 27, 5, 79, 1, 193, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 104, 243, 43, 115, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 182, 193, 32, 251, 5, 189, 35, 25, 12, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 99, 190, 128, 178, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 147, 131,
+    114, 216, 68, 204, 57, 31, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
+    134, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    72, 49, 210, 248, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 87, 24, 255, 212, 205, 237, 120, 57, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 85, 158, 27, 112, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 108, 53, 202, 66, 69, 64, 211, 203, 12, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 144, 76, 58, 247, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 22, 231,
+    179, 203, 218, 156, 64, 157, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
+    128, 1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    144, 24, 238, 60, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 196, 95, 184, 97, 94, 165, 98, 28, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115,
+    101, 101, 100, 6, 133, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 142, 112, 70, 86, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
+    112, 101, 8, 1, 2, 105, 100, 5, 2, 21, 138, 98, 236, 208, 52, 197, 8, 3, 97, 100, 114, 12, 8,
+    4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 213, 29, 219, 208, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 101, 229, 157, 96, 78, 149,
+    79, 16, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 64,
+    44, 139, 100, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 7, 203, 58, 177, 99, 127, 167, 27, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 104, 63, 131, 202, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 177, 178, 79, 176, 17, 114, 135, 128, 12, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 58, 211, 149, 42, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 254, 82, 29, 104,
+    17, 156, 165, 19, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0,
+    0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 66, 235,
+    43, 120, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 140, 48, 91, 174, 88, 66, 197, 27, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 209, 182, 235, 183, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 20, 196,
+    15, 35, 230, 214, 44, 106, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 107, 213, 91, 83, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 43, 184, 117, 30, 44, 105, 95, 158, 12, 112, 114, 117, 110, 105,
+    110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 134, 122, 101, 227, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 34, 132, 26, 158, 228, 246, 37, 122, 12,
+    112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 135, 1, 0, 0, 8, 114, 112, 99,
+    95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 5, 144, 96, 90, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
+    112, 101, 8, 1, 2, 105, 100, 5, 53, 151, 49, 57, 210, 176, 123, 26, 12, 3, 97, 100, 114, 12, 8,
+    4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255,
+    255, 45, 129, 183, 236, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
+    2, 2, 105, 100, 5, 114, 226, 183, 173, 149, 80, 29, 207, 12, 112, 114, 117, 110, 105, 110, 103,
+    95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 96, 48, 253, 223, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 138, 193, 53, 95, 29, 24, 29, 67, 12, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 209, 141, 137, 143, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 142, 18, 157,
+    53, 139, 6, 171, 205, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1,
+    0, 0, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 195,
+    252, 42, 19, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 11, 193, 221, 161, 77, 26, 136, 247, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161,
+    70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 136, 34,
+    220, 232, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 188, 71, 195, 46, 165, 220, 100, 247, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 136, 50, 23, 101, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 142, 104, 228, 114, 184, 184, 155, 149, 12, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 79, 23, 184, 106, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 9, 143,
+    211, 219, 201, 209, 78, 126, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
+    131, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    70, 172, 62, 186, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 90, 74, 7, 27, 88, 104, 96, 63, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 109, 95, 105, 112, 6, 83, 48, 63, 194, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 157, 41, 130, 225, 209, 57, 190, 227, 16, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 192, 18, 141, 11, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 47, 149, 84, 128,
+    33, 31, 39, 27, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0,
+    8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 198,
+    251, 83, 134, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105,
+    100, 5, 240, 74, 2, 160, 144, 140, 44, 91, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70,
+    12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 173, 255,
+    205, 142, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 13, 193, 21, 155, 217, 217, 26, 41, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101,
+    101, 100, 6, 130, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 170, 39, 103, 46, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 110, 132, 154, 110, 72, 158, 220, 125, 16, 3, 97, 100, 114, 12, 8,
+    4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 149, 202, 95, 149, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 227, 35, 58, 212, 189, 211,
+    121, 171, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 8,
+    114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 20, 200, 83, 5, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 76, 28, 183, 239, 120, 19, 32, 226, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 67, 191, 0, 235, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 46, 88, 119,
+    219, 137, 174, 116, 168, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 178, 174, 135, 72, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 196, 76, 198, 87, 49, 143, 40, 85, 12, 112, 114, 117, 110, 105, 110,
+    103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 139, 99, 124, 170,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 144,
+    217, 56, 205, 38, 203, 209, 20, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 46, 124, 180, 230, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 26, 138, 117, 131, 113, 28, 20, 89, 12, 112, 114, 117,
+    110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 134, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185, 64, 105, 115, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 35, 92, 145, 24, 195, 195, 28,
+    241, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 136, 36,
+    57, 103, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 121, 160, 232, 102, 215, 85, 156, 52, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 168, 235, 93, 184, 6, 109, 95, 112, 111, 114, 116, 7, 51, 212, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 158, 89, 86, 15, 219, 4, 128, 76, 12, 112, 114, 117,
+    110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 66, 205, 213, 214, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 169, 3, 234, 0, 122, 41, 147, 3,
+    8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 141, 98, 255,
+    143, 6, 109, 95, 112, 111, 114, 116, 7, 177, 214, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
+    73, 127, 80, 122, 34, 152, 103, 89, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 172, 104, 177, 190, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 55, 98, 90,
+    98, 247, 151, 65, 145, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 77, 172, 230, 31, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 37, 18, 78, 110, 19,
+    172, 149, 68, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    162, 218, 65, 156, 6, 109, 95, 112, 111, 114, 116, 7, 204, 71, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 34, 212, 28, 89, 219, 217, 198, 30, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 109, 145, 130, 189, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 210, 119, 203, 62, 62, 33, 221, 76, 8, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 73, 97, 224, 138, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 233, 45,
+    113, 162, 132, 119, 238, 222, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 221, 234, 36, 39, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 231, 98, 155, 99,
+    120, 161, 139, 47, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 68, 109, 164, 26, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 143, 41, 243, 191, 26, 133, 133, 148, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 99, 234,
+    74, 130, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100,
+    5, 75, 225, 51, 71, 34, 147, 131, 93, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 172, 104, 177, 190, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 55, 98, 90, 98, 247, 151, 65, 145, 8, 114, 112, 99,
+    95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 89, 39, 107, 63, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
+    112, 101, 8, 1, 2, 105, 100, 5, 253, 235, 42, 162, 145, 123, 118, 118, 8, 3, 97, 100, 114, 12,
+    8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 86, 19, 164, 20, 6, 109, 95, 112, 111,
+    114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 254, 16, 203, 169, 195, 236,
+    197, 71, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 45,
+    132, 245, 124, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 203, 217, 203, 62, 235, 68, 79, 246, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169,
+    70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 78, 47, 1,
+    42, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
+    62, 193, 188, 52, 253, 250, 177, 78, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 24, 9, 196, 18, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 181, 175, 149, 70, 255, 230, 120, 144, 12, 112, 114, 117,
+    110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 81, 174, 147, 70, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 139, 117, 247, 251, 252, 120,
+    207, 122, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24,
+    46, 131, 31, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 254, 139, 52, 145, 46, 192, 114, 218, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 109, 95, 105, 112, 6, 96, 41, 220, 52, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 132, 117, 138, 75, 120, 0, 85, 225, 8, 114, 112, 99,
+    95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 185, 104, 122, 37, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 134, 166, 173, 179, 232, 66, 0, 248, 8, 114, 112, 99, 95,
+    112, 111, 114, 116, 7, 161, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 71, 127, 156, 63, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 173, 130, 134, 129, 151, 73, 173, 117, 8, 114, 112, 99,
+    95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 132, 248, 211, 188, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 243, 244, 6, 201, 85, 211, 59, 11, 8, 3, 97, 100, 114, 12,
+    8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 221, 121, 132, 104, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 164, 8, 50, 88, 16,
+    202, 71, 19, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    98, 177, 226, 32, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 13, 149, 116, 215, 92, 35, 192, 137, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 107, 191, 99, 95, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 35, 130, 252, 209, 208, 87, 41, 16, 8, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 82, 64, 20, 76, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 96, 72, 181,
+    187, 232, 78, 92, 185, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
+    105, 112, 6, 188, 68, 50, 194, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 135, 253, 6, 30, 218, 46, 47, 159, 8, 114, 112, 99, 95, 112, 111,
+    114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 159, 100, 254, 56, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
+    8, 1, 2, 105, 100, 5, 106, 11, 135, 247, 255, 124, 31, 27, 8, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 23, 128, 248, 240, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 86, 157, 138, 8, 39, 38, 167,
+    237, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 95, 142,
+    45, 13, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 68, 53, 120, 88, 65, 106, 55, 62, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 139, 99, 124, 170, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 144, 217, 56, 205, 38, 203, 209, 20, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 149, 102, 137, 246, 6, 109, 95,
+    112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 252, 192, 231, 60,
+    235, 176, 190, 192, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
+    112, 6, 82, 66, 187, 193, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
+    1, 2, 105, 100, 5, 167, 249, 42, 214, 200, 13, 147, 63, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
+    100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 54, 36, 174, 4, 6, 109, 95, 112, 111, 114, 116, 7,
+    160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 1, 91, 183, 129, 113, 151, 228, 218, 12,
+    3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 192, 9, 184, 123,
+    6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 237,
+    203, 144, 195, 93, 91, 36, 253, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100,
+    6, 132, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
+    6, 146, 190, 105, 218, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
+    2, 105, 100, 5, 106, 83, 142, 16, 72, 134, 65, 203, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 202, 112, 0, 98, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 254, 101, 149, 141, 81, 187, 225, 93, 12, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 45, 67, 217, 103, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 0, 127,
+    172, 186, 143, 155, 201, 145, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 16, 3, 97,
+    100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 85, 247, 59, 77, 6, 109,
+    95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 226, 116, 172,
+    104, 27, 129, 198, 120, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 129,
+    1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 172, 93, 53, 9, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 41, 95, 47, 152, 22, 90, 49, 102,
+    12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 16, 3, 97, 100,
+    114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 255, 255, 71, 237, 129, 0, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 2, 2, 105, 100, 5, 115, 63, 166, 229, 245, 229, 122, 239, 12, 112, 114, 117, 110, 105,
+    110, 103, 95, 115, 101, 101, 100, 6, 135, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
+    169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 82,
+    121, 36, 202, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 216, 167, 229, 40, 235, 137, 72, 52, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115,
+    101, 101, 100, 6, 131, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 118, 36, 228, 156, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
+    112, 101, 8, 1, 2, 105, 100, 5, 33, 1, 180, 80, 243, 161, 76, 54, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
+    73, 235, 168, 79, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2,
+    105, 100, 5, 120, 102, 85, 162, 254, 2, 48, 208, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
+    114, 12, 8, 4, 109, 95, 105, 112, 6, 204, 12, 201, 36, 6, 109, 95, 112, 111, 114, 116, 7, 160,
+    70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 239, 100, 244, 177, 67, 52, 196, 181, 8, 3,
+    97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 216, 249, 90, 57, 6,
+    109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 222, 93,
+    114, 147, 216, 204, 49, 151, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
+    95, 105, 112, 6, 83, 137, 41, 10, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
+    101, 8, 1, 2, 105, 100, 5, 180, 29, 120, 16, 126, 72, 126, 35, 8, 3, 97, 100, 114, 12, 8, 4,
+    97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 63, 143, 48, 18, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 193, 47, 218, 50, 81, 222, 85,
+    43, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 152, 89,
+    216, 153, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
+    5, 31, 11, 87, 67, 7, 192, 1, 13, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 77, 95, 229, 224, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 160, 119, 196, 130, 136, 75, 140, 54, 8, 3, 97, 100, 114,
+    12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 82, 69, 12, 29, 6, 109, 95, 112,
+    111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 208, 247, 146, 51, 217,
+    73, 193, 226, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
+    49, 12, 239, 156, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 146, 129, 163, 49, 89, 133, 44, 64, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
+    169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185,
+    240, 242, 36, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
+    100, 5, 197, 219, 86, 2, 247, 136, 229, 29, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
+    12, 8, 4, 109, 95, 105, 112, 6, 134, 122, 61, 72, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
+    4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 29, 223, 119, 202, 113, 165, 81, 7, 8, 114, 112,
+    99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
+    8, 4, 109, 95, 105, 112, 6, 195, 154, 242, 41, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
+    116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 177, 250, 184, 155, 105, 57, 83, 47, 8, 114, 112, 99,
+    95, 112, 111, 114, 116, 7, 169, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
+    109, 95, 105, 112, 6, 99, 248, 2, 29, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
+    112, 101, 8, 1, 2, 105, 100, 5, 10, 209, 218, 249, 58, 74, 147, 253, 12, 112, 114, 117, 110,
+    105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114,
+    116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
+    6, 51, 75, 64, 249, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
+    105, 100, 5, 183, 101, 253, 145, 35, 156, 129, 45, 12, 112, 114, 117, 110, 105, 110, 103, 95,
+    115, 101, 101, 100, 6, 134, 1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
+    4, 109, 95, 105, 112, 6, 185, 203, 56, 7, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
+    121, 112, 101, 8, 1, 2, 105, 100, 5, 241, 202, 217, 195, 142, 200, 163, 55, 12, 112, 114, 117,
+    110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97,
+    100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 136, 243, 145, 198, 6, 109, 95, 112, 111, 114,
+    116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 53, 77, 124, 92, 86, 55, 9, 77,
+    8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 46, 28, 204,
+    223, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
+    183, 162, 58, 143, 188, 33, 92, 33, 9, 110, 111, 100, 101, 95, 100, 97, 116, 97, 12, 20, 7,
+    109, 121, 95, 112, 111, 114, 116, 6, 160, 70, 0, 0, 10, 110, 101, 116, 119, 111, 114, 107, 95,
+    105, 100, 10, 64, 18, 48, 241, 113, 97, 4, 65, 97, 23, 49, 0, 130, 22, 161, 161, 16, 7, 112,
+    101, 101, 114, 95, 105, 100, 5, 172, 170, 135, 122, 19, 151, 202, 83, 8, 114, 112, 99, 95, 112,
+    111, 114, 116, 7, 169, 70, 13, 115, 117, 112, 112, 111, 114, 116, 95, 102, 108, 97, 103, 115,
+    6, 1, 0, 0, 0, 12, 112, 97, 121, 108, 111, 97, 100, 95, 100, 97, 116, 97, 12, 24, 21, 99, 117,
+    109, 117, 108, 97, 116, 105, 118, 101, 95, 100, 105, 102, 102, 105, 99, 117, 108, 116, 121, 5,
+    25, 78, 56, 125, 251, 152, 97, 3, 27, 99, 117, 109, 117, 108, 97, 116, 105, 118, 101, 95, 100,
+    105, 102, 102, 105, 99, 117, 108, 116, 121, 95, 116, 111, 112, 54, 52, 5, 0, 0, 0, 0, 0, 0, 0,
+    0, 14, 99, 117, 114, 114, 101, 110, 116, 95, 104, 101, 105, 103, 104, 116, 5, 127, 88, 42, 0,
+    0, 0, 0, 0, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 6,
+    116, 111, 112, 95, 105, 100, 10, 128, 64, 120, 0, 114, 218, 233, 18, 49, 8, 89, 154, 159, 101,
+    133, 242, 71, 77, 3, 247, 182, 219, 181, 216, 193, 135, 23, 186, 168, 207, 119, 86, 235, 11,
+    116, 111, 112, 95, 118, 101, 114, 115, 105, 111, 110, 8, 16,
+];
+
+// NOTE: This test case caused a stack overflow in the epee_encoding library.
+// The depth and the sizes are now checked with DecodeLimits
 //
 #[test]
 fn p2p_handshake_res() {
-    let bytes = [
-        1, 17, 1, 1, 1, 1, 2, 1, 1, 12, 18, 108, 111, 99, 97, 108, 95, 112, 101, 101, 114, 108,
-        105, 115, 116, 95, 110, 101, 119, 140, 233, 3, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 12, 8, 4, 109, 95, 105, 112, 6, 107, 200, 125, 246, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 136, 104, 95, 61, 247, 215, 186, 88,
-        8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 184, 166,
-        147, 112, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
-        100, 5, 104, 35, 236, 206, 255, 98, 40, 247, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 12, 8, 4, 109, 95, 105, 112, 6, 94, 23, 147, 238, 6, 109, 95, 112, 111, 114, 116, 7,
-        64, 9, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 204, 213, 226, 217, 141, 205, 23, 171,
-        12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 65, 144, 135, 125, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 142, 211, 149, 235, 68, 76, 58, 116,
-        8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 85, 25, 198, 233, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 239, 118, 5, 143, 245, 175, 79,
-        79, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 128,
-        199, 45, 242, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 53, 123, 81, 158, 161, 118, 88, 49, 8, 114, 112, 99, 95, 112, 111, 114, 116,
-        7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
-        6, 49, 12, 239, 176, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 231, 115, 240, 106, 33, 156, 129, 168, 12, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 72, 83, 1, 29, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 163, 230, 52, 192, 29,
-        175, 184, 138, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0,
-        0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 24, 46, 131, 31, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 254, 139, 52, 145, 46, 192, 114, 218,
-        12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 144, 76, 58, 247, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 22, 231, 179, 203, 218, 156, 64, 157,
-        12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 255, 255, 46, 4, 27, 39, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 2, 2, 105, 100, 5, 190, 246, 23, 85, 132, 101, 46, 120, 12, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 93, 95, 228, 51, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 76, 184,
-        195, 205, 119, 55, 205, 223, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 89, 36, 78, 249, 6,
-        109, 95, 112, 111, 114, 116, 7, 40, 230, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 146,
-        84, 128, 27, 45, 173, 110, 233, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 159, 203, 65, 163, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 36, 140, 127, 49, 80, 143, 75, 170, 8, 114, 112,
-        99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 89, 163, 225, 22, 6, 109, 95, 112, 111, 114, 116, 7, 160,
-        70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 202, 167, 239, 104, 191, 245, 206, 114,
-        12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 51, 38, 53, 106, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 26,
-        47, 180, 164, 45, 11, 4, 137, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 98, 28, 252, 84, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 109, 204, 107, 31, 61, 229, 138, 61, 12, 112, 114,
-        117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 16, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 35, 142, 222, 141, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 74, 23, 146,
-        223, 157, 219, 198, 96, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
-        135, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 168, 119, 134, 54, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 210, 66, 174,
-        96, 27, 169, 14, 141, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 24, 246, 38, 201, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 29, 41, 255, 104, 159, 199, 248, 152, 8, 3, 97, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 255, 255, 5, 161, 65, 201, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 2, 2, 105, 100, 5, 97, 17, 31, 195, 85, 103, 254, 119, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 76, 89, 170, 52, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 191, 241, 183, 135,
-        6, 109, 186, 8, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 184, 153, 84, 228, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 211, 11, 202, 189,
-        127, 164, 118, 13, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 66, 42, 82, 58, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 209, 217, 109, 131, 173, 212, 169, 138, 12, 112, 114, 117, 110,
-        105, 110, 103, 95, 115, 101, 101, 100, 6, 135, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114,
-        116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 70, 93, 166, 98, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
-        8, 1, 2, 105, 100, 5, 35, 43, 193, 141, 6, 220, 30, 115, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 99, 17, 41, 20, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 56, 45, 55, 79, 80, 200, 73,
-        54, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 130,
-        180, 116, 214, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 101, 87, 57, 29, 16, 210, 163, 114, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 148, 163, 81, 34, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 118, 209, 9, 62, 203, 181, 144,
-        55, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 67,
-        85, 160, 39, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 8, 54, 90, 44, 129, 203, 30, 229, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
-        161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        162, 218, 65, 223, 6, 109, 95, 112, 111, 114, 116, 7, 163, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 8, 95, 132, 221, 20, 172, 44, 131, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 83, 78, 142, 101, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 53, 254, 109, 4, 238, 54, 246, 88,
-        12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 18, 169,
-        212, 248, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
-        100, 5, 239, 181, 136, 45, 48, 0, 23, 111, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161,
-        70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 138,
-        201, 50, 228, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 100, 143, 145, 170, 221, 44, 134, 40, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 96, 2, 101, 49, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 175, 162, 146, 137, 96, 124, 179,
-        123, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 16, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 194, 55, 15, 70, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 200, 230, 46, 22, 61,
-        113, 146, 207, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0,
-        0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
-        24, 144, 51, 151, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2,
-        2, 105, 100, 5, 92, 225, 176, 105, 146, 76, 126, 246, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 51, 89, 43, 165, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 227, 169, 44, 172, 218, 27,
-        134, 20, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10,
-        64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 138, 201, 50, 11, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 77, 159, 65, 201, 154, 203,
-        137, 189, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        37, 19, 221, 165, 6, 109, 95, 112, 111, 114, 116, 7, 101, 225, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 16, 236, 61, 198, 179, 107, 122, 230, 12, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 211, 104, 25, 222, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 141, 18, 233, 140, 158,
-        178, 117, 2, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0,
-        0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 79,
-        225, 232, 232, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 25, 41, 192, 83, 178, 152, 49, 39, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 45, 44, 224, 220, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 43, 192, 235, 1, 175, 51, 244,
-        209, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 44, 198, 160,
-        153, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
-        5, 255, 168, 200, 48, 53, 200, 228, 34, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 85, 214, 100, 187, 6, 109, 95, 112, 111, 114, 116, 7, 160,
-        70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 237, 118, 70, 209, 142, 252, 124, 105, 12,
-        112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 104, 52, 169, 164, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 7,
-        180, 60, 132, 208, 115, 38, 78, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 188, 214, 129, 182, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 6,
-        110, 8, 120, 108, 89, 215, 109, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 81, 79, 96, 58, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 29, 242, 12, 182, 32, 31, 164, 147, 8, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 50, 246, 254, 202, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 200,
-        152, 38, 71, 152, 168, 148, 130, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 75, 168, 213, 32, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 89, 66, 99, 25, 23, 47, 236, 47, 12, 3, 97, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 80, 217, 6, 170, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 25, 65, 171,
-        45, 39, 65, 82, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 16, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 27, 96, 242, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 11, 5,
-        248, 49, 46, 9, 138, 92, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
-        133, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 72, 239, 241, 242, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 77, 192, 17,
-        161, 34, 186, 222, 123, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
-        95, 105, 112, 6, 144, 217, 70, 139, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 144, 82, 41, 18, 67, 173, 224, 251, 12, 112, 114, 117,
-        110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 129, 1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 216, 239, 98, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 135, 151, 188, 127, 59,
-        20, 95, 161, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 134, 1, 0,
-        0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 91, 65,
-        190, 110, 6, 109, 95, 112, 111, 114, 116, 7, 169, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
-        100, 5, 96, 33, 127, 102, 27, 224, 111, 185, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 134, 122,
-        61, 72, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105,
-        100, 5, 29, 223, 119, 202, 113, 165, 81, 7, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
-        169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        37, 15, 95, 252, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 219, 64, 229, 115, 235, 126, 213, 173, 12, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 178, 62, 9, 149, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 151, 68, 31, 187, 63, 163,
-        185, 18, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 23, 115, 236, 180, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 205, 12, 26, 50, 3, 165,
-        53, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        71, 78, 74, 138, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 206, 121, 181, 161, 29, 110, 53, 18, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 73, 216, 104, 205, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 243, 216, 53, 122, 144, 182,
-        239, 255, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        85, 241, 6, 106, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 249, 166, 55, 155, 51, 129, 124, 12, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 79, 199, 36, 70, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 43, 26, 130, 68, 205, 92, 233, 62,
-        12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 73, 113, 165, 163, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 125, 189, 171, 153, 83, 86, 245, 62,
-        12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 137, 118, 214, 52,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
-        28, 94, 51, 157, 189, 105, 178, 114, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 109, 95, 105, 112, 6, 75, 82, 185, 108, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
-        4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 76, 196, 240, 134, 116, 222, 86, 142, 8, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 255, 255, 68, 118, 240, 19, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
-        4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 213, 192, 104, 120, 74, 119, 165, 194, 8, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 51, 83, 179,
-        106, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
-        5, 27, 33, 186, 12, 194, 25, 75, 31, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 109, 95, 105, 112, 6, 178, 162, 153, 157, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
-        4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 4, 66, 90, 148, 24, 80, 49, 170, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 92, 170, 92, 127, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 213,
-        45, 208, 229, 123, 172, 173, 241, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
-        4, 109, 95, 105, 112, 6, 193, 72, 32, 187, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 50, 33, 217, 128, 43, 173, 61, 224, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 218, 108, 30, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 115,
-        212, 228, 103, 43, 138, 93, 217, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 163, 172, 90, 168, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 135, 51, 116, 56, 95, 226, 184, 221, 12, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 255, 255, 5, 39, 91, 20, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 2, 2, 105, 100, 5, 10, 81, 175, 58, 168, 40, 36, 112, 12, 112, 114, 117,
-        110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255,
-        255, 45, 142, 235, 46, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
-        8, 2, 2, 105, 100, 5, 183, 57, 59, 58, 77, 216, 203, 0, 12, 112, 114, 117, 110, 105, 110,
-        103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 12, 8, 4, 109, 95, 105, 112, 6, 185, 157, 160, 119, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 115, 192, 241, 99, 119, 228, 23, 129,
-        20, 114, 112, 99, 95, 99, 114, 101, 100, 105, 116, 115, 95, 112, 101, 114, 95, 104, 97,
-        115, 104, 6, 0, 0, 64, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 5, 129, 54, 131, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 180,
-        136, 168, 128, 35, 8, 229, 160, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 185, 10, 68, 240, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 105,
-        127, 240, 231, 209, 2, 20, 136, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 32, 221, 180, 229, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 107, 14, 156, 75, 92, 180, 143, 152, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 218, 88, 21, 188, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 51,
-        234, 157, 182, 229, 103, 39, 88, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 5, 161, 73, 151, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 209, 162, 121, 222, 241, 125, 142, 156, 8, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 162, 226, 61, 8, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 6, 64,
-        210, 168, 209, 134, 142, 135, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 104, 238, 128, 24, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 252, 199, 163, 220, 223, 220, 85, 167, 12, 112,
-        114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 114, 112, 99, 95,
-        112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 89, 147, 109, 91, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 90, 73, 143, 67, 50, 148, 50, 51, 8, 114, 112, 99, 95,
-        112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 60, 225, 148, 220, 6, 109, 95, 112, 111, 114, 116, 7, 169, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 246, 236, 191, 85, 5, 65, 238, 143, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 84, 255, 235, 77, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 204,
-        171, 86, 75, 156, 202, 194, 17, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 172, 86, 75, 188, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 102, 18, 220, 13, 62, 231, 50, 12, 12, 112, 114, 117,
-        110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 47, 154, 174, 191, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 142, 90, 23, 242, 187,
-        113, 45, 135, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
-        6, 162, 253, 155, 86, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 115, 140, 18, 86, 209, 1, 136, 191, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 192, 42, 253, 215, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 172, 48, 83, 16, 150,
-        49, 179, 117, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
-        6, 213, 239, 210, 144, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
-        8, 1, 2, 105, 100, 5, 191, 25, 48, 149, 62, 193, 163, 51, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 213, 251, 235, 252, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 30, 76, 94, 11, 19, 53,
-        222, 168, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        82, 101, 246, 21, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 79, 214, 218, 34, 12, 184, 235, 67, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 88, 19, 42, 245, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 252, 148, 134, 97, 173, 59, 174,
-        174, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 94,
-        63, 121, 77, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 218, 250, 149, 133, 175, 197, 220, 9, 12, 112, 114, 117, 110, 105, 110, 103,
-        95, 115, 101, 101, 100, 6, 134, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 24, 49, 114, 184, 6, 109, 95, 112, 111, 114, 116, 7, 160,
-        70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 71, 65, 104, 43, 162, 145, 13, 85, 12, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 76, 185, 18, 32,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 7,
-        159, 72, 170, 11, 137, 53, 215, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101,
-        100, 6, 135, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 68, 73, 201, 40, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 172, 77, 69, 220, 58, 59, 195, 192, 8, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        255, 255, 84, 255, 235, 77, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 2, 2, 105, 100, 5, 204, 171, 86, 75, 156, 202, 194, 17, 8, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 165, 227, 34, 105, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 154, 230, 103, 180,
-        139, 210, 99, 101, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 35, 236, 113, 185, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 49, 104, 224, 149, 51, 239, 29, 76, 16, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 165, 22, 12, 133, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 246, 50, 244, 226,
-        219, 198, 24, 105, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130,
-        1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 94, 156, 174, 100, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 183, 117, 141, 111, 95,
-        90, 58, 204, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
-        6, 5, 255, 100, 208, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 125, 25, 139, 251, 139, 116, 172, 24, 8, 114, 112, 99, 95, 112, 111,
-        114, 116, 7, 161, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 86, 94, 156, 130, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 181, 75, 11, 73, 214, 93, 48, 250, 12, 112, 114, 117, 110,
-        105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114,
-        116, 7, 169, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 93, 95, 230, 245, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 84, 157, 243, 239,
-        183, 35, 54, 0, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1,
-        0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 86, 14, 58, 98, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 131, 145, 164, 127, 110,
-        147, 147, 166, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 52, 15, 111, 162, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 196, 112, 61, 95, 122, 150, 241, 171, 8, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        255, 255, 135, 181, 164, 52, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 2, 2, 105, 100, 5, 52, 20, 78, 121, 22, 214, 174, 79, 12, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        255, 255, 159, 69, 121, 11, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 2, 2, 105, 100, 5, 205, 44, 250, 54, 193, 111, 54, 245, 12, 112, 114, 117, 110,
-        105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185, 241, 197, 18, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 161, 144, 210, 66, 72,
-        38, 182, 154, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 24, 165, 109, 172, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 139, 244, 62,
-        181, 35, 101, 190, 130, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 95, 168, 216, 7, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 113, 28, 114, 184, 37, 154, 224, 95, 8, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 138, 201, 120, 71, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 189, 245, 211, 35,
-        245, 56, 24, 89, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 91, 206, 14, 98, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101,
-        8, 1, 2, 105, 100, 5, 43, 84, 225, 240, 218, 249, 219, 193, 8, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 61, 245, 153, 149, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 111, 172, 135, 36, 174,
-        143, 223, 112, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 31, 28, 25, 99, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 129, 160, 102, 202, 168,
-        75, 67, 246, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
-        6, 217, 168, 137, 14, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 198, 134, 120, 100, 110, 70, 149, 242, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 65, 21, 134, 181, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 192, 152, 237, 245, 35, 179,
-        133, 92, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        82, 29, 15, 7, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 93, 238, 22, 254, 190, 156, 170, 53, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 100, 36, 215, 42, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 225, 217, 27, 239, 55, 77, 130,
-        218, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 221,
-        138, 212, 98, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 221, 115, 168, 71, 115, 113, 123, 226, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 95, 158, 72, 195, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 169, 55, 84, 253, 73, 235, 126, 1,
-        8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 47, 5,
-        146, 126, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
-        100, 5, 36, 7, 132, 185, 123, 44, 79, 9, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 199, 102, 237,
-        44, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100,
-        5, 23, 191, 140, 80, 125, 5, 151, 123, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 109, 95, 105, 112, 6, 69, 163, 90, 6, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 2, 215, 175, 128, 152, 162, 90, 120, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 147, 135, 136, 35,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
-        151, 75, 230, 186, 160, 198, 175, 124, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 83, 137, 41, 10,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5,
-        180, 29, 120, 16, 126, 72, 126, 35, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 109, 95, 105, 112, 6, 45, 62, 207, 10, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 131, 144, 70, 177, 35, 135, 112, 205, 12, 112,
-        114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 134, 1, 0, 0, 8, 114, 112, 99, 95,
-        112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 98, 225, 91, 191, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 19, 165, 172, 148, 44, 52, 241, 105, 12, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 88, 212, 32, 151, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 183, 237,
-        176, 171, 69, 241, 107, 86, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 255, 255, 51, 68, 214, 143, 6, 109, 95, 112, 111, 114, 116, 7, 48, 17, 4,
-        116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 206, 248, 161, 56, 86, 125, 195, 243, 16, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 152, 67, 175, 91, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 17,
-        83, 56, 240, 65, 163, 170, 158, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101,
-        100, 6, 130, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 216, 232, 100, 178, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 167,
-        109, 78, 13, 99, 252, 5, 228, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 146, 59, 0, 16, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 177, 145,
-        118, 10, 116, 105, 64, 90, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
-        95, 105, 112, 6, 185, 10, 68, 240, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 105, 127, 240, 231, 209, 2, 20, 136, 12, 3, 97, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 89, 2, 142, 180, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 1, 21, 198,
-        102, 36, 134, 38, 249, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 12, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 255, 255, 158, 140, 230, 233, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 2, 2, 105, 100, 5, 0, 77, 233, 80, 62, 71, 201, 59, 12, 112, 114, 117,
-        110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 78, 106, 12, 87, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 119, 128, 137, 92, 63,
-        246, 81, 72, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
-        6, 145, 255, 252, 43, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 209, 7, 199, 121, 195, 30, 91, 168, 8, 114, 112, 99, 95, 112, 111, 114,
-        116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 71, 219, 43, 129, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 165, 255, 243, 173, 120, 214, 100, 239, 12, 112, 114, 117, 110,
-        105, 110, 103, 95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
-        65, 21, 137, 242, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2,
-        2, 105, 100, 5, 56, 81, 223, 232, 243, 53, 150, 199, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 76, 183, 153, 43, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 2, 169, 223, 201, 166, 219, 0,
-        108, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 146,
-        190, 226, 170, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 184, 126, 145, 86, 17, 171, 106, 193, 12, 112, 114, 117, 110, 105, 110, 103,
-        95, 115, 101, 101, 100, 6, 130, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 99, 234, 74, 130, 6, 109, 95, 112, 111, 114, 116, 7, 160,
-        70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 75, 225, 51, 71, 34, 147, 131, 93, 8, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 34, 243, 233,
-        242, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
-        5, 241, 169, 58, 47, 204, 254, 64, 194, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 37, 1, 201, 53, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
-        4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 218, 61, 215, 233, 72, 244, 247, 85, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 100, 0, 31, 209, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 254,
-        223, 214, 77, 175, 11, 224, 27, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 38, 242, 201, 74, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 55, 246, 56, 7, 244, 33, 111, 185, 8, 3, 97, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 178, 128, 226, 166, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 100, 250,
-        203, 11, 176, 225, 42, 24, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
-        95, 105, 112, 6, 45, 238, 67, 67, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 5, 230, 42, 159, 3, 83, 146, 72, 8, 114, 112, 99, 95, 112,
-        111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
-        95, 105, 112, 6, 178, 237, 47, 93, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 217, 28, 236, 27, 5, 79, 1, 193, 8, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 104, 243, 43, 115, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 182, 193, 32, 251,
-        5, 189, 35, 25, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 99, 190, 128, 178, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 147, 131, 114, 216, 68, 204, 57, 31, 12, 112, 114, 117, 110,
-        105, 110, 103, 95, 115, 101, 101, 100, 6, 134, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 72, 49, 210, 248, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 87, 24, 255, 212, 205, 237,
-        120, 57, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        85, 158, 27, 112, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 108, 53, 202, 66, 69, 64, 211, 203, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 144, 76, 58, 247, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 22, 231, 179, 203, 218, 156, 64,
-        157, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 12, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 144, 24, 238,
-        60, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
-        5, 196, 95, 184, 97, 94, 165, 98, 28, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101,
-        101, 100, 6, 133, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
-        95, 105, 112, 6, 142, 112, 70, 86, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 2, 21, 138, 98, 236, 208, 52, 197, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 213, 29, 219, 208, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 101, 229, 157,
-        96, 78, 149, 79, 16, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 64, 44, 139, 100, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 7, 203, 58, 177, 99, 127, 167, 27, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 104, 63, 131, 202, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 177, 178, 79,
-        176, 17, 114, 135, 128, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
-        95, 105, 112, 6, 58, 211, 149, 42, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 254, 82, 29, 104, 17, 156, 165, 19, 12, 112, 114, 117, 110,
-        105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 66, 235, 43, 120, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 140, 48, 91, 174, 88, 66,
-        197, 27, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10,
-        64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 209, 182, 235, 183, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 20, 196, 15, 35, 230, 214,
-        44, 106, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        107, 213, 91, 83, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 43, 184, 117, 30, 44, 105, 95, 158, 12, 112, 114, 117, 110, 105, 110, 103,
-        95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 134, 122, 101, 227, 6, 109, 95, 112, 111, 114, 116, 7, 160,
-        70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 34, 132, 26, 158, 228, 246, 37, 122, 12,
-        112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 135, 1, 0, 0, 8, 114, 112,
-        99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 5, 144, 96, 90, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
-        4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 53, 151, 49, 57, 210, 176, 123, 26, 12, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 255, 255, 45, 129, 183, 236, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 114, 226, 183, 173, 149, 80, 29, 207, 12, 112,
-        114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 3, 97, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 96, 48, 253, 223, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 138, 193, 53,
-        95, 29, 24, 29, 67, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 209, 141, 137, 143, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 142, 18, 157, 53, 139, 6, 171, 205, 12, 112, 114, 117, 110,
-        105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 12, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 195, 252, 42, 19, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 11, 193, 221, 161, 77, 26,
-        136, 247, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 136, 34, 220, 232, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 188, 71, 195, 46,
-        165, 220, 100, 247, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 136, 50, 23, 101, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 142, 104, 228, 114, 184, 184, 155, 149, 12, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 79, 23, 184, 106, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 9, 143,
-        211, 219, 201, 209, 78, 126, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100,
-        6, 131, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 70, 172, 62, 186, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 90, 74, 7, 27, 88, 104, 96, 63, 8, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 83, 48, 63, 194, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 157, 41, 130, 225, 209,
-        57, 190, 227, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 192, 18, 141, 11, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 47, 149, 84, 128, 33, 31, 39, 27, 12, 112, 114, 117, 110, 105,
-        110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0, 0, 8, 114, 112, 99, 95, 112, 111, 114, 116,
-        7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 198, 251, 83, 134, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 240, 74, 2, 160, 144,
-        140, 44, 91, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 173, 255, 205, 142, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 13, 193, 21,
-        155, 217, 217, 26, 41, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6,
-        130, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112,
-        6, 170, 39, 103, 46, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 110, 132, 154, 110, 72, 158, 220, 125, 16, 3, 97, 100, 114, 12, 8, 4,
-        97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 149, 202, 95, 149, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 227, 35, 58, 212, 189,
-        211, 121, 171, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0,
-        0, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 20, 200, 83, 5, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 76, 28, 183, 239, 120, 19,
-        32, 226, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        67, 191, 0, 235, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 46, 88, 119, 219, 137, 174, 116, 168, 12, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 178, 174, 135, 72, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 196, 76, 198, 87, 49,
-        143, 40, 85, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 128, 1, 0,
-        0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 139, 99, 124, 170, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 144, 217, 56, 205, 38, 203, 209, 20,
-        12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 46, 124,
-        180, 230, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
-        100, 5, 26, 138, 117, 131, 113, 28, 20, 89, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115,
-        101, 101, 100, 6, 134, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 185, 64, 105, 115, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 35, 92, 145, 24, 195, 195, 28, 241, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 136, 36, 57, 103, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 121,
-        160, 232, 102, 215, 85, 156, 52, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
-        4, 109, 95, 105, 112, 6, 168, 235, 93, 184, 6, 109, 95, 112, 111, 114, 116, 7, 51, 212, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 158, 89, 86, 15, 219, 4, 128, 76, 12, 112, 114,
-        117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 66, 205, 213, 214, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 169, 3, 234,
-        0, 122, 41, 147, 3, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 141, 98, 255, 143, 6, 109, 95, 112, 111, 114, 116, 7, 177, 214, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 73, 127, 80, 122, 34, 152, 103, 89, 12, 3, 97, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 255, 255, 172, 104, 177, 190, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 2, 2, 105, 100, 5, 55, 98, 90, 98, 247, 151, 65, 145, 8, 114, 112, 99,
-        95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
-        4, 109, 95, 105, 112, 6, 77, 172, 230, 31, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 37, 18, 78, 110, 19, 172, 149, 68, 8, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 162, 218, 65, 156, 6,
-        109, 95, 112, 111, 114, 116, 7, 204, 71, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 34,
-        212, 28, 89, 219, 217, 198, 30, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 109, 145, 130, 189, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 210, 119, 203, 62, 62, 33, 221, 76, 8, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 73, 97, 224, 138, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 233,
-        45, 113, 162, 132, 119, 238, 222, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 221, 234, 36, 39, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 231,
-        98, 155, 99, 120, 161, 139, 47, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 68, 109, 164, 26, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 143, 41, 243, 191, 26, 133, 133, 148, 8, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 255, 255, 99, 234, 74, 130, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 2, 2, 105, 100, 5, 75, 225, 51, 71, 34, 147, 131, 93, 12, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 172, 104, 177, 190, 6,
-        109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 55,
-        98, 90, 98, 247, 151, 65, 145, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 89, 39, 107, 63,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
-        253, 235, 42, 162, 145, 123, 118, 118, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 109, 95, 105, 112, 6, 86, 19, 164, 20, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 254, 16, 203, 169, 195, 236, 197, 71, 12, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 45, 132, 245, 124,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
-        203, 217, 203, 62, 235, 68, 79, 246, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70,
-        8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 78, 47, 1,
-        42, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100,
-        5, 62, 193, 188, 52, 253, 250, 177, 78, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 24, 9, 196, 18, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
-        4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 181, 175, 149, 70, 255, 230, 120, 144, 12,
-        112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 8, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 81, 174, 147, 70, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 139, 117,
-        247, 251, 252, 120, 207, 122, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 24, 46, 131, 31, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 254, 139, 52, 145, 46, 192, 114, 218, 12, 3, 97, 100,
-        114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 96, 41, 220, 52, 6, 109,
-        95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 132, 117,
-        138, 75, 120, 0, 85, 225, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185, 104, 122, 37,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
-        134, 166, 173, 179, 232, 66, 0, 248, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70,
-        12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 71, 127,
-        156, 63, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
-        100, 5, 173, 130, 134, 129, 151, 73, 173, 117, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
-        169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        132, 248, 211, 188, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 243, 244, 6, 201, 85, 211, 59, 11, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 221, 121, 132, 104, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 164, 8, 50, 88, 16, 202,
-        71, 19, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        98, 177, 226, 32, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 13, 149, 116, 215, 92, 35, 192, 137, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 107, 191, 99, 95, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 35, 130, 252, 209, 208, 87, 41,
-        16, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 82,
-        64, 20, 76, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 96, 72, 181, 187, 232, 78, 92, 185, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 188, 68, 50, 194, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 135, 253, 6, 30, 218, 46, 47, 159,
-        8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 161, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 159, 100, 254, 56, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 106, 11, 135, 247, 255, 124,
-        31, 27, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        23, 128, 248, 240, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1,
-        2, 105, 100, 5, 86, 157, 138, 8, 39, 38, 167, 237, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 95, 142, 45, 13, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 68, 53, 120, 88, 65, 106, 55, 62,
-        8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 139, 99,
-        124, 170, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105,
-        100, 5, 144, 217, 56, 205, 38, 203, 209, 20, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 12, 8, 4, 109, 95, 105, 112, 6, 149, 102, 137, 246, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 252, 192, 231, 60, 235, 176, 190,
-        192, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 82,
-        66, 187, 193, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 167, 249, 42, 214, 200, 13, 147, 63, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 54, 36, 174, 4, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 1, 91, 183, 129, 113, 151, 228,
-        218, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 192,
-        9, 184, 123, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 237, 203, 144, 195, 93, 91, 36, 253, 12, 112, 114, 117, 110, 105, 110, 103,
-        95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114,
-        12, 8, 4, 109, 95, 105, 112, 6, 146, 190, 105, 218, 6, 109, 95, 112, 111, 114, 116, 7, 160,
-        70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 106, 83, 142, 16, 72, 134, 65, 203, 8, 3,
-        97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 202, 112, 0, 98,
-        6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5,
-        254, 101, 149, 141, 81, 187, 225, 93, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 109, 95, 105, 112, 6, 45, 67, 217, 103, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70,
-        4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 0, 127, 172, 186, 143, 155, 201, 145, 8, 114,
-        112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100,
-        114, 12, 8, 4, 109, 95, 105, 112, 6, 85, 247, 59, 77, 6, 109, 95, 112, 111, 114, 116, 7,
-        160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 226, 116, 172, 104, 27, 129, 198,
-        120, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 129, 1, 0, 0, 8,
-        114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 172, 93, 53, 9, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 41, 95, 47, 152, 22, 90, 49, 102,
-        12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 132, 1, 0, 0, 16, 3, 97,
-        100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100, 100, 114, 10, 64, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 255, 255, 71, 237, 129, 0, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 115, 63, 166, 229, 245, 229, 122, 239, 12, 112,
-        114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 135, 1, 0, 0, 8, 114, 112, 99, 95,
-        112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 82, 121, 36, 202, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 216, 167, 229, 40, 235, 137, 72, 52, 12, 112, 114,
-        117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 131, 1, 0, 0, 8, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 118, 36, 228, 156, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 33, 1, 180,
-        80, 243, 161, 76, 54, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 10, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 73, 235, 168, 79, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 2, 2, 105, 100, 5, 120, 102, 85,
-        162, 254, 2, 48, 208, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 204, 12, 201, 36, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121,
-        112, 101, 8, 1, 2, 105, 100, 5, 239, 100, 244, 177, 67, 52, 196, 181, 8, 3, 97, 100, 114,
-        12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 216, 249, 90, 57, 6, 109, 95,
-        112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 222, 93, 114,
-        147, 216, 204, 49, 151, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95,
-        105, 112, 6, 83, 137, 41, 10, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 180, 29, 120, 16, 126, 72, 126, 35, 8, 3, 97, 100, 114, 12, 8,
-        4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 63, 143, 48, 18, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 193, 47, 218, 50,
-        81, 222, 85, 43, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 152, 89, 216, 153, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 31, 11, 87, 67, 7, 192, 1, 13, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 77, 95, 229, 224, 6, 109, 95, 112, 111, 114,
-        116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 160, 119, 196, 130, 136, 75,
-        140, 54, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        82, 69, 12, 29, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2,
-        105, 100, 5, 208, 247, 146, 51, 217, 73, 193, 226, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 49, 12, 239, 156, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 146, 129, 163, 49, 89, 133, 44,
-        64, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7, 169, 70, 8, 3, 97, 100, 114, 12, 8, 4, 97,
-        100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185, 240, 242, 36, 6, 109, 95, 112, 111,
-        114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 197, 219, 86, 2, 247,
-        136, 229, 29, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105,
-        112, 6, 134, 122, 61, 72, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112,
-        101, 8, 1, 2, 105, 100, 5, 29, 223, 119, 202, 113, 165, 81, 7, 8, 114, 112, 99, 95, 112,
-        111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109,
-        95, 105, 112, 6, 195, 154, 242, 41, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 177, 250, 184, 155, 105, 57, 83, 47, 8, 114, 112, 99,
-        95, 112, 111, 114, 116, 7, 169, 70, 16, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12,
-        8, 4, 109, 95, 105, 112, 6, 99, 248, 2, 29, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4,
-        116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 10, 209, 218, 249, 58, 74, 147, 253, 12, 112,
-        114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133, 1, 0, 0, 8, 114, 112, 99, 95,
-        112, 111, 114, 116, 7, 169, 70, 12, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4,
-        109, 95, 105, 112, 6, 51, 75, 64, 249, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116,
-        121, 112, 101, 8, 1, 2, 105, 100, 5, 183, 101, 253, 145, 35, 156, 129, 45, 12, 112, 114,
-        117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 134, 1, 0, 0, 12, 3, 97, 100, 114, 12,
-        8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 185, 203, 56, 7, 6, 109, 95, 112,
-        111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 241, 202, 217, 195,
-        142, 200, 163, 55, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 133,
-        1, 0, 0, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100, 100, 114, 12, 8, 4, 109, 95, 105, 112, 6,
-        136, 243, 145, 198, 6, 109, 95, 112, 111, 114, 116, 7, 160, 70, 4, 116, 121, 112, 101, 8,
-        1, 2, 105, 100, 5, 53, 77, 124, 92, 86, 55, 9, 77, 8, 3, 97, 100, 114, 12, 8, 4, 97, 100,
-        100, 114, 12, 8, 4, 109, 95, 105, 112, 6, 46, 28, 204, 223, 6, 109, 95, 112, 111, 114, 116,
-        7, 160, 70, 4, 116, 121, 112, 101, 8, 1, 2, 105, 100, 5, 183, 162, 58, 143, 188, 33, 92,
-        33, 9, 110, 111, 100, 101, 95, 100, 97, 116, 97, 12, 20, 7, 109, 121, 95, 112, 111, 114,
-        116, 6, 160, 70, 0, 0, 10, 110, 101, 116, 119, 111, 114, 107, 95, 105, 100, 10, 64, 18, 48,
-        241, 113, 97, 4, 65, 97, 23, 49, 0, 130, 22, 161, 161, 16, 7, 112, 101, 101, 114, 95, 105,
-        100, 5, 172, 170, 135, 122, 19, 151, 202, 83, 8, 114, 112, 99, 95, 112, 111, 114, 116, 7,
-        169, 70, 13, 115, 117, 112, 112, 111, 114, 116, 95, 102, 108, 97, 103, 115, 6, 1, 0, 0, 0,
-        12, 112, 97, 121, 108, 111, 97, 100, 95, 100, 97, 116, 97, 12, 24, 21, 99, 117, 109, 117,
-        108, 97, 116, 105, 118, 101, 95, 100, 105, 102, 102, 105, 99, 117, 108, 116, 121, 5, 25,
-        78, 56, 125, 251, 152, 97, 3, 27, 99, 117, 109, 117, 108, 97, 116, 105, 118, 101, 95, 100,
-        105, 102, 102, 105, 99, 117, 108, 116, 121, 95, 116, 111, 112, 54, 52, 5, 0, 0, 0, 0, 0, 0,
-        0, 0, 14, 99, 117, 114, 114, 101, 110, 116, 95, 104, 101, 105, 103, 104, 116, 5, 127, 88,
-        42, 0, 0, 0, 0, 0, 12, 112, 114, 117, 110, 105, 110, 103, 95, 115, 101, 101, 100, 6, 130,
-        1, 0, 0, 6, 116, 111, 112, 95, 105, 100, 10, 128, 64, 120, 0, 114, 218, 233, 18, 49, 8, 89,
-        154, 159, 101, 133, 242, 71, 77, 3, 247, 182, 219, 181, 216, 193, 135, 23, 186, 168, 207,
-        119, 86, 235, 11, 116, 111, 112, 95, 118, 101, 114, 115, 105, 111, 110, 8, 16,
-    ];
-    let handshake: HandshakeResponse = epee_encoding::from_bytes(&mut &bytes[..]).unwrap();
+    let handshake: HandshakeResponse =
+        from_bytes(HANDSHAKE_RESPONSE_BYTES, &DecodeLimits::default()).unwrap();
 
     let basic_node_data = NodeData {
         my_port: 18080,
@@ -1076,7 +1512,7 @@ fn p2p_handshake_res() {
             18, 48, 241, 113, 97, 4, 65, 97, 23, 49, 0, 130, 22, 161, 161, 16,
         ],
         peer_id: 6037804360359455404,
-        support_flags: 1, // PeerSupportFlags::from(1_u32),
+        support_flags: SupportFlags::FLUFFY_BLOCKS,
         rpc_port: 18089,
         rpc_credits_per_hash: 0,
     };
@@ -1097,9 +1533,98 @@ fn p2p_handshake_res() {
     assert_eq!(core_sync_data, handshake.payload_data);
     assert_eq!(250, handshake.local_peerlist_new.len());
 
-    let mut encoded_bytes = epee_encoding::to_bytes(&handshake).unwrap();
+    let encoded_bytes = to_bytes(&handshake).unwrap();
 
-    let handshake_2: HandshakeResponse = epee_encoding::from_bytes(&mut encoded_bytes).unwrap();
+    let handshake_2: HandshakeResponse =
+        from_bytes(&encoded_bytes, &DecodeLimits::default()).unwrap();
 
     assert_eq!(handshake, handshake_2);
+    // Same bytes as monerod
+    assert_eq!(encoded_bytes, HANDSHAKE_RESPONSE_BYTES);
+}
+
+#[test]
+fn p2p_peer_list() {
+    // Only the peer list of the handshake response
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct PeerList {
+        local_peerlist_new: Vec<PeerListEntryBase>,
+    }
+
+    let peer_list: PeerList =
+        from_bytes(HANDSHAKE_RESPONSE_BYTES, &DecodeLimits::default()).unwrap();
+    let peers = &peer_list.local_peerlist_new;
+    assert_eq!(250, peers.len());
+
+    assert_eq!(
+        NetworkAddress::from(SocketAddr::from(([107, 200, 125, 246], 18080))),
+        peers[0].adr
+    );
+    assert_eq!(0, peers[0].rpc_port);
+
+    assert_eq!(
+        NetworkAddress::from(SocketAddr::from((
+            Ipv4Addr::new(65, 144, 135, 125).to_ipv6_mapped(),
+            18080
+        ))),
+        peers[3].adr
+    );
+    assert_eq!(18089, peers[3].rpc_port);
+    assert_eq!(0, peers[3].pruning_seed);
+
+    assert_eq!(
+        NetworkAddress::from(SocketAddr::from(([72, 83, 1, 29], 18080))),
+        peers[7].adr
+    );
+    assert_eq!(389, peers[7].pruning_seed);
+
+    // The optional fields are not written, so the peer list is the
+    // same as the one of monerod
+    let encoded = to_bytes(&peer_list).unwrap();
+    assert_eq!(encoded[10..], HANDSHAKE_RESPONSE_BYTES[10..encoded.len()]);
+
+    // All the peers of monerod are public
+    let mut response = HandshakeResponse {
+        local_peerlist_new: peer_list.local_peerlist_new,
+        ..Default::default()
+    };
+    assert_eq!(250, response.routable_peers().count());
+    response.local_peerlist_new[0].adr = "192.168.1.10:18080".parse().unwrap();
+    assert_eq!(249, response.routable_peers().count());
+}
+
+#[test]
+fn p2p_new_transactions() {
+    let notification = NewTransactions {
+        padding: vec![0; 3],
+        dandelionpp_fluff: true,
+        txs: vec![vec![0x02, 0x00], Vec::new()],
+    };
+
+    // Fields in alphabetical order, as monerod writes them. A fluff
+    // relay has only _ and txs
+    let bytes = to_bytes(&notification).unwrap();
+    assert_eq!([8, 1, b'_', 10, 12, 0, 0, 0, 3, b't'], bytes[9..19]);
+    assert_eq!(
+        notification,
+        from_bytes(&bytes, &DecodeLimits::default()).unwrap()
+    );
+
+    // The stem relays write the flag
+    let stem = NewTransactions {
+        dandelionpp_fluff: false,
+        ..notification
+    };
+    let bytes = to_bytes(&stem).unwrap();
+    assert_eq!([12, 1, b'_'], bytes[9..12]);
+    assert_eq!(stem, from_bytes(&bytes, &DecodeLimits::default()).unwrap());
+
+    // Without padding and flag, it is a fluff relay
+    let bytes = [
+        0x1, 0x11, 0x1, 0x1, 0x1, 0x1, 0x2, 0x1, 0x1, 0x4, 0x3, b't', b'x', b's', 0x8a, 0x4, 0x8,
+        0xaa, 0xbb,
+    ];
+    let decoded: NewTransactions = from_bytes(&bytes, &DecodeLimits::default()).unwrap();
+    assert_eq!(vec![vec![0xaa, 0xbb]], decoded.txs);
+    assert!(decoded.dandelionpp_fluff);
 }
@@ -0,0 +1,245 @@
+// Transaction
+//
+// Id of the transactions relayed by the nodes. The id of a version 1
+// transaction is the Keccak-256 of its blob. The id of a version 2
+// (RingCT) transaction is the Keccak-256 of the hashes of its prefix,
+// its RingCT base and its prunable part, as get_transaction_hash of
+// monerod. The blob is only read up to the end of the base, the rest
+// is the prunable part
+//
+
+use sha3::{Digest, Keccak256};
+
+use crate::error::ConnectNodeError;
+
+// Tags of the inputs and the outputs
+const TXIN_GEN: u8 = 0xff;
+const TXIN_TO_KEY: u8 = 0x02;
+const TXOUT_TO_KEY: u8 = 0x02;
+const TXOUT_TO_TAGGED_KEY: u8 = 0x03;
+
+// Types of the RingCT signatures
+const RCT_TYPE_NULL: u8 = 0;
+const RCT_TYPE_FULL: u8 = 1;
+const RCT_TYPE_SIMPLE: u8 = 2;
+const RCT_TYPE_BULLETPROOF: u8 = 3;
+const RCT_TYPE_BULLETPROOF_PLUS: u8 = 6;
+
+const KEY_SIZE: usize = 32;
+
+fn keccak(in_bytes: &[u8]) -> [u8; 32] {
+    Keccak256::digest(in_bytes).into()
+}
+
+struct TxReader<'a> {
+    blob: &'a [u8],
+    position: usize,
+}
+
+impl TxReader<'_> {
+    fn skip(&mut self, in_length: usize) -> Result<(), ConnectNodeError> {
+        match self.position.checked_add(in_length) {
+            Some(end) if end <= self.blob.len() => {
+                self.position = end;
+                Ok(())
+            }
+            _ => Err(ConnectNodeError::InvalidTransaction(
+                "Unexpected end of blob",
+            )),
+        }
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ConnectNodeError> {
+        let value = *self
+            .blob
+            .get(self.position)
+            .ok_or(ConnectNodeError::InvalidTransaction(
+                "Unexpected end of blob",
+            ))?;
+        self.position += 1;
+
+        Ok(value)
+    }
+
+    // 7 bits per byte, the least significant first
+    fn read_varint(&mut self) -> Result<u64, ConnectNodeError> {
+        let mut value: u64 = 0;
+
+        for shift in (0..64).step_by(7) {
+            let b = self.read_u8()?;
+            value |= u64::from(b & 0x7f) << shift;
+            if b & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(ConnectNodeError::InvalidTransaction("Varint too long"))
+    }
+
+    fn read_count(&mut self) -> Result<usize, ConnectNodeError> {
+        usize::try_from(self.read_varint()?)
+            .map_err(|_| ConnectNodeError::InvalidTransaction("Count too long"))
+    }
+
+    // Version and numbers of inputs and outputs
+    fn read_prefix(&mut self) -> Result<(u64, usize, usize), ConnectNodeError> {
+        let version = self.read_varint()?;
+        // unlock_time
+        self.read_varint()?;
+
+        let inputs = self.read_count()?;
+        for _ in 0..inputs {
+            match self.read_u8()? {
+                // Height of the block
+                TXIN_GEN => {
+                    self.read_varint()?;
+                }
+                // Amount, key offsets and key image
+                TXIN_TO_KEY => {
+                    self.read_varint()?;
+                    for _ in 0..self.read_count()? {
+                        self.read_varint()?;
+                    }
+                    self.skip(KEY_SIZE)?;
+                }
+                _ => return Err(ConnectNodeError::InvalidTransaction("Unknown input type")),
+            }
+        }
+
+        let outputs = self.read_count()?;
+        for _ in 0..outputs {
+            // Amount
+            self.read_varint()?;
+            match self.read_u8()? {
+                TXOUT_TO_KEY => self.skip(KEY_SIZE)?,
+                // Key and view tag
+                TXOUT_TO_TAGGED_KEY => self.skip(KEY_SIZE + 1)?,
+                _ => return Err(ConnectNodeError::InvalidTransaction("Unknown output type")),
+            }
+        }
+
+        let extra = self.read_count()?;
+        self.skip(extra)?;
+
+        Ok((version, inputs, outputs))
+    }
+
+    // Type, fee, pseudo outputs, ecdh info and output commitments
+    fn read_rct_base(
+        &mut self,
+        in_inputs: usize,
+        in_outputs: usize,
+    ) -> Result<u8, ConnectNodeError> {
+        let rct_type = self.read_u8()?;
+        if rct_type == RCT_TYPE_NULL {
+            return Ok(rct_type);
+        }
+        if rct_type > RCT_TYPE_BULLETPROOF_PLUS {
+            return Err(ConnectNodeError::InvalidTransaction("Unknown RingCT type"));
+        }
+
+        // Fee
+        self.read_varint()?;
+        if rct_type == RCT_TYPE_SIMPLE {
+            self.skip_keys(in_inputs, KEY_SIZE)?;
+        }
+        // Since Bulletproof2 only 8 bytes of the amount are sent
+        let ecdh_size = match rct_type {
+            RCT_TYPE_FULL | RCT_TYPE_SIMPLE | RCT_TYPE_BULLETPROOF => 2 * KEY_SIZE,
+            _ => 8,
+        };
+        self.skip_keys(in_outputs, ecdh_size)?;
+        self.skip_keys(in_outputs, KEY_SIZE)?;
+
+        Ok(rct_type)
+    }
+
+    fn skip_keys(&mut self, in_count: usize, in_size: usize) -> Result<(), ConnectNodeError> {
+        let length = in_count
+            .checked_mul(in_size)
+            .ok_or(ConnectNodeError::InvalidTransaction("Count too long"))?;
+        self.skip(length)
+    }
+}
+
+// Id of a transaction, as shown by monerod and the explorers
+pub fn tx_hash(in_blob: &[u8]) -> Result<[u8; 32], ConnectNodeError> {
+    let mut r = TxReader {
+        blob: in_blob,
+        position: 0,
+    };
+
+    let (version, inputs, outputs) = r.read_prefix()?;
+    if version == 1 {
+        return Ok(keccak(in_blob));
+    }
+    let prefix_end = r.position;
+
+    let rct_type = r.read_rct_base(inputs, outputs)?;
+    let base_end = r.position;
+
+    let mut hashes = [0; 3 * KEY_SIZE];
+    hashes[..KEY_SIZE].copy_from_slice(&keccak(&in_blob[..prefix_end]));
+    hashes[KEY_SIZE..2 * KEY_SIZE].copy_from_slice(&keccak(&in_blob[prefix_end..base_end]));
+    // The prunable hash of the coinbase transactions is 0
+    if rct_type != RCT_TYPE_NULL {
+        hashes[2 * KEY_SIZE..].copy_from_slice(&keccak(&in_blob[base_end..]));
+    }
+
+    Ok(keccak(&hashes))
+}
+
+#[test]
+fn transaction_hash() {
+    // Coinbase of the mainnet genesis block (GENESIS_TX of monerod)
+    let genesis = hex::decode(
+        "013c01ff0001ffffffffffff03029b2e4c0281c0b02e7c53291a94d1d0cbff8883f8024f5142ee494ffbbd08807121017767aafcde9be00dcfd098715ebcf7f410daebc582fda69d24a28e9d0bc890d1",
+    )
+    .unwrap();
+    let mut r = TxReader {
+        blob: &genesis,
+        position: 0,
+    };
+    assert_eq!((1, 1, 1), r.read_prefix().unwrap());
+    assert_eq!(genesis.len(), r.position);
+    assert_eq!(keccak(&genesis), tx_hash(&genesis).unwrap());
+
+    // Version 2 coinbase: one tagged output and no RingCT signatures
+    let mut prefix = vec![0x02, 0x3c, 0x01, TXIN_GEN, 0x80, 0x01, 0x01, 0x05];
+    prefix.push(TXOUT_TO_TAGGED_KEY);
+    prefix.extend_from_slice(&[0x11; KEY_SIZE + 1]);
+    prefix.extend_from_slice(&[0x02, 0x00, 0x00]);
+    let mut coinbase = prefix.clone();
+    coinbase.push(RCT_TYPE_NULL);
+
+    let mut hashes = keccak(&prefix).to_vec();
+    hashes.extend_from_slice(&keccak(&[RCT_TYPE_NULL]));
+    hashes.extend_from_slice(&[0; KEY_SIZE]);
+    assert_eq!(keccak(&hashes), tx_hash(&coinbase).unwrap());
+
+    // CLSAG with 1 input of 2 ring members and 2 outputs
+    let mut prefix = vec![0x02, 0x00, 0x01, TXIN_TO_KEY, 0x00, 0x02, 0x81, 0x01, 0x05];
+    prefix.extend_from_slice(&[0x22; KEY_SIZE]);
+    prefix.push(0x02);
+    for _ in 0..2 {
+        prefix.extend_from_slice(&[0x00, TXOUT_TO_TAGGED_KEY]);
+        prefix.extend_from_slice(&[0x33; KEY_SIZE + 1]);
+    }
+    prefix.extend_from_slice(&[0x01, 0x00]);
+    let mut base = vec![5, 0xe0, 0x01];
+    base.extend_from_slice(&[0x44; 2 * 8 + 2 * KEY_SIZE]);
+    let prunable = [0x55; 100];
+    let tx = [prefix.as_slice(), &base, &prunable].concat();
+
+    let mut hashes = keccak(&prefix).to_vec();
+    hashes.extend_from_slice(&keccak(&base));
+    hashes.extend_from_slice(&keccak(&prunable));
+    assert_eq!(keccak(&hashes), tx_hash(&tx).unwrap());
+    assert_ne!(keccak(&tx), tx_hash(&tx).unwrap());
+
+    // Cut in the output commitments
+    assert!(matches!(
+        tx_hash(&tx[..prefix.len() + base.len() - 1]),
+        Err(ConnectNodeError::InvalidTransaction(_))
+    ));
+}